@@ -0,0 +1,259 @@
+//! Derive macro for structs whose fields are collectors from the
+//! [collected](https://docs.rs/collected/) crate.
+//!
+//! See [`Collect`](macro@Collect) for usage.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    spanned::Spanned,
+    Data, DeriveInput, Error, Expr, Fields, Ident, Member, Token, Type,
+};
+
+/// Derives `Default`, `FromIterator<Item>` and `Extend<Item>` for a struct of collectors.
+///
+/// The item type is given by the `#[collect(item = Type)]` attribute on the struct.
+/// Each incoming item is pushed to every field of the struct, which must implement
+/// `Collector`. The value that a field receives is selected by a field attribute.
+///
+/// - No attribute: the field receives a clone of the whole item.
+/// - `#[collect(field = name)]`: the field receives a clone of `item.name`. Tuple
+///   items are accessed by index, for example `#[collect(field = 0)]`.
+/// - `#[collect(with = expr)]`: the field receives `expr(&item)`, where `expr` is a
+///   function path such as `path::to::func` or a closure.
+///
+/// If the last field has no attribute, it receives the item itself rather than a
+/// clone, so that the item type needs to be `Clone` only when other fields take
+/// the whole item.
+///
+/// ```rust
+/// use collected::{Collect, Count, MaxVal, MinVal, SumVal};
+///
+/// #[derive(Clone)]
+/// struct Record {
+///     latency: u64,
+///     bytes: usize,
+/// }
+///
+/// fn is_slow(record: &Record) -> usize {
+///     (record.latency > 100) as usize
+/// }
+///
+/// #[derive(Collect)]
+/// #[collect(item = Record)]
+/// struct Stats {
+///     count: Count<Record>,
+///     #[collect(field = latency)]
+///     min_latency: MinVal<u64>,
+///     #[collect(field = latency)]
+///     max_latency: MaxVal<u64>,
+///     #[collect(field = bytes)]
+///     total_bytes: SumVal<usize>,
+///     #[collect(with = is_slow)]
+///     slow: SumVal<usize>,
+/// }
+///
+/// let stats: Stats = vec![
+///     Record { latency: 20, bytes: 3 },
+///     Record { latency: 150, bytes: 5 },
+///     Record { latency: 70, bytes: 1 },
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// assert_eq!(stats.count.get(), 3);
+/// assert_eq!(stats.min_latency.unwrap(), 20);
+/// assert_eq!(stats.max_latency.unwrap(), 150);
+/// assert_eq!(stats.total_bytes.into_inner(), 9);
+/// assert_eq!(stats.slow.into_inner(), 1);
+/// ```
+#[proc_macro_derive(Collect, attributes(collect))]
+pub fn derive_collect(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// The value selected from an item for a field.
+enum Projection {
+    Whole,
+    Field(Member),
+    With(Box<Expr>),
+}
+
+/// The argument of a `#[collect(key = value)]` attribute.
+enum Arg {
+    Item(Box<Type>),
+    Field(Member),
+    With(Box<Expr>),
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+
+        let arg = match key.to_string().as_str() {
+            "item" => Arg::Item(Box::new(input.parse()?)),
+            "field" => Arg::Field(input.parse()?),
+            "with" => Arg::With(Box::new(input.parse()?)),
+            _ => {
+                return Err(Error::new(
+                    key.span(),
+                    "expect one of `item`, `field` or `with`",
+                ))
+            }
+        };
+        Ok(arg)
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let DeriveInput {
+        attrs,
+        ident,
+        generics,
+        data,
+        ..
+    } = input;
+
+    let shape = match data {
+        Data::Struct(data) => data.fields,
+        Data::Enum(data) => {
+            return Err(Error::new(
+                data.enum_token.span(),
+                "Collect can only be derived for structs",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span(),
+                "Collect can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut item_ty = None;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("collect")) {
+        match attr.parse_args()? {
+            Arg::Item(ty) if item_ty.is_none() => item_ty = Some(ty),
+            Arg::Item(ty) => return Err(Error::new(ty.span(), "duplicated `item` argument")),
+            _ => {
+                return Err(Error::new(
+                    attr.span(),
+                    "expect `#[collect(item = Type)]` on the struct",
+                ))
+            }
+        }
+    }
+    let item_ty = item_ty.ok_or_else(|| {
+        Error::new(
+            ident.span(),
+            "missing `#[collect(item = Type)]` attribute on the struct",
+        )
+    })?;
+
+    let fields: Vec<(Member, Projection)> = shape
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let member = match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(index.into()),
+            };
+
+            let mut projection = None;
+            for attr in field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident("collect"))
+            {
+                if projection.is_some() {
+                    return Err(Error::new(attr.span(), "duplicated `collect` attribute"));
+                }
+
+                projection = Some(match attr.parse_args()? {
+                    Arg::Field(member) => Projection::Field(member),
+                    Arg::With(expr) => Projection::With(expr),
+                    Arg::Item(_) => {
+                        return Err(Error::new(
+                            attr.span(),
+                            "expect `field` or `with` argument on a field",
+                        ))
+                    }
+                });
+            }
+
+            Ok((member, projection.unwrap_or(Projection::Whole)))
+        })
+        .collect::<syn::Result<_>>()?;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let default_body = match &shape {
+        Fields::Unit => quote! { Self },
+        Fields::Named(_) => {
+            let members = fields.iter().map(|(member, _)| member);
+            quote! {
+                Self {
+                    #(#members: ::core::default::Default::default(),)*
+                }
+            }
+        }
+        Fields::Unnamed(_) => {
+            let defaults = fields
+                .iter()
+                .map(|_| quote! { ::core::default::Default::default() });
+            quote! { Self(#(#defaults),*) }
+        }
+    };
+
+    // Every field takes a clone, except that the last one takes the whole item
+    // itself, as `Tee` does. A field of the item is always cloned, since moving
+    // it out fails for items implementing `Drop`.
+    let last = fields.len().saturating_sub(1);
+    let pushes = fields
+        .iter()
+        .enumerate()
+        .map(|(index, (member, projection))| {
+            let value = match projection {
+                Projection::Whole if index == last => quote! { item },
+                Projection::Whole => quote! { ::core::clone::Clone::clone(&item) },
+                Projection::Field(field) => quote! { ::core::clone::Clone::clone(&item.#field) },
+                Projection::With(expr) => quote! { (#expr)(&item) },
+            };
+            quote! {
+                ::collected::Collector::push(&mut self.#member, #value);
+            }
+        });
+
+    let expanded = quote! {
+        impl #impl_generics ::core::default::Default for #ident #ty_generics #where_clause {
+            fn default() -> Self {
+                #default_body
+            }
+        }
+
+        impl #impl_generics ::core::iter::FromIterator<#item_ty> for #ident #ty_generics #where_clause {
+            fn from_iter<T: ::core::iter::IntoIterator<Item = #item_ty>>(iter: T) -> Self {
+                let mut collector = <Self as ::core::default::Default>::default();
+                ::core::iter::Extend::extend(&mut collector, iter);
+                collector
+            }
+        }
+
+        impl #impl_generics ::core::iter::Extend<#item_ty> for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn extend<T: ::core::iter::IntoIterator<Item = #item_ty>>(&mut self, iter: T) {
+                for item in iter {
+                    #(#pushes)*
+                }
+            }
+        }
+    };
+
+    Ok(expanded)
+}
@@ -0,0 +1,133 @@
+use collected::{Collect, Count, First, Last, MaxVal, MinVal, SumVal, TopK};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Record {
+    name: &'static str,
+    score: u32,
+}
+
+fn name_len(record: &Record) -> usize {
+    record.name.len()
+}
+
+#[derive(Collect)]
+#[collect(item = Record)]
+struct Stats {
+    count: Count<Record>,
+    first: First<Record>,
+    #[collect(field = score)]
+    min_score: MinVal<u32>,
+    #[collect(field = score)]
+    max_score: MaxVal<u32>,
+    #[collect(field = score)]
+    top_scores: TopK<u32, 2>,
+    #[collect(with = name_len)]
+    total_name_len: SumVal<usize>,
+}
+
+#[derive(Collect)]
+#[collect(item = (i32, i32))]
+struct PairStats(
+    #[collect(field = 0)] MinVal<i32>,
+    #[collect(field = 1)] MaxVal<i32>,
+);
+
+#[derive(Collect)]
+#[collect(item = A)]
+struct Bounds<A>
+where
+    A: Ord + Clone + Default,
+{
+    min: MinVal<A>,
+    max: MaxVal<A>,
+}
+
+/// An item that is not `Clone`, which only the last field takes by value.
+#[derive(Debug, PartialEq)]
+struct Token(u32);
+
+impl Drop for Token {
+    fn drop(&mut self) {}
+}
+
+fn token_id(token: &Token) -> u32 {
+    token.0
+}
+
+#[derive(Collect)]
+#[collect(item = Token)]
+struct Tokens {
+    #[collect(with = token_id)]
+    total: SumVal<u32>,
+    #[collect(with = |token: &Token| token.0 * 2)]
+    doubled: SumVal<u32>,
+    last: Last<Token>,
+}
+
+#[derive(Collect)]
+#[collect(item = Token)]
+struct TokenIds {
+    #[collect(field = 0)]
+    last_id: Last<u32>,
+}
+
+fn records() -> Vec<Record> {
+    vec![
+        Record {
+            name: "alice",
+            score: 7,
+        },
+        Record {
+            name: "bob",
+            score: 9,
+        },
+        Record {
+            name: "carol",
+            score: 3,
+        },
+    ]
+}
+
+#[test]
+fn collect_named_test() {
+    let mut records = records().into_iter();
+    let mut stats: Stats = records.by_ref().take(2).collect();
+    assert_eq!(stats.count.get(), 2);
+    assert_eq!(stats.min_score.get(), Some(&7));
+
+    stats.extend(records);
+    assert_eq!(stats.count.get(), 3);
+    assert_eq!(stats.first.get().map(|record| record.name), Some("alice"));
+    assert_eq!(stats.min_score.unwrap(), 3);
+    assert_eq!(stats.max_score.unwrap(), 9);
+    assert_eq!(stats.top_scores.into_sorted_vec(), vec![9, 7]);
+    assert_eq!(stats.total_name_len.into_inner(), 13);
+}
+
+#[test]
+fn collect_unnamed_test() {
+    let PairStats(min, max) = vec![(3, 1), (-2, 8), (5, 4)].into_iter().collect();
+    assert_eq!(min.unwrap(), -2);
+    assert_eq!(max.unwrap(), 8);
+}
+
+#[test]
+fn collect_generic_test() {
+    let bounds: Bounds<_> = vec!["b", "a", "c"].into_iter().collect();
+    assert_eq!(bounds.min.unwrap(), "a");
+    assert_eq!(bounds.max.unwrap(), "c");
+
+    let bounds = Bounds::<u8>::default();
+    assert_eq!(bounds.min.into_inner(), None);
+}
+
+#[test]
+fn collect_move_last_test() {
+    let tokens: Tokens = vec![Token(1), Token(2), Token(4)].into_iter().collect();
+    assert_eq!(tokens.total.into_inner(), 7);
+    assert_eq!(tokens.doubled.into_inner(), 14);
+    assert_eq!(tokens.last.into_inner(), Some(Token(4)));
+
+    let ids: TokenIds = vec![Token(1), Token(2)].into_iter().collect();
+    assert_eq!(ids.last_id.into_inner(), Some(2));
+}
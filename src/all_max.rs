@@ -0,0 +1,149 @@
+use crate::common::*;
+
+/// A collection that keeps every item equal to the maximum.
+///
+/// Tied items are kept in the order they arrive, and the buffer is cleared
+/// when a strictly greater item arrives. Unlike [`MaxVal`](crate::MaxVal), no
+/// tied item is dropped.
+///
+/// ```rust
+/// use collected::AllMax;
+///
+/// let max: AllMax<_> = vec![2, 5, 1, 5, 3].into_iter().collect();
+/// assert_eq!(max.into_vec(), vec![5, 5]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AllMax<A> {
+    inner: Vec<A>,
+}
+
+impl<A> AllMax<A> {
+    pub fn get(&self) -> &[A] {
+        &self.inner
+    }
+
+    pub fn into_vec(self) -> Vec<A> {
+        self.inner
+    }
+}
+
+impl<A> Default for AllMax<A> {
+    fn default() -> Self {
+        Self { inner: Vec::new() }
+    }
+}
+
+impl<A> From<AllMax<A>> for Vec<A> {
+    fn from(collector: AllMax<A>) -> Self {
+        collector.inner
+    }
+}
+
+impl<A> FromIterator<A> for AllMax<A>
+where
+    A: Ord,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut all = Self::default();
+        all.extend(iter);
+        all
+    }
+}
+
+impl<A> Extend<A> for AllMax<A>
+where
+    A: Ord,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A> Collector for AllMax<A>
+where
+    A: Ord,
+{
+    type Item = A;
+    type Output = Vec<A>;
+
+    fn push(&mut self, item: A) {
+        let ord = match self.inner.first() {
+            Some(best) => item.cmp(best),
+            None => Ordering::Equal,
+        };
+        match ord {
+            Ordering::Less => {}
+            Ordering::Equal => self.inner.push(item),
+            Ordering::Greater => {
+                self.inner.clear();
+                self.inner.push(item);
+            }
+        }
+    }
+
+    fn merge(&mut self, mut other: Self) {
+        let ord = match (self.inner.first(), other.inner.first()) {
+            (Some(best), Some(item)) => item.cmp(best),
+            _ => Ordering::Equal,
+        };
+        match ord {
+            Ordering::Less => {}
+            Ordering::Equal => self.inner.append(&mut other.inner),
+            Ordering::Greater => self.inner = other.inner,
+        }
+    }
+
+    fn finish(self) -> Vec<A> {
+        self.inner
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for AllMax<A>
+where
+    A: Ord + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for AllMax<A>
+where
+    A: Ord + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_max_test() {
+        let mut max: AllMax<_> = vec![3, 1, 3, 2].into_iter().collect();
+        assert_eq!(max.get(), &[3, 3]);
+
+        max.extend(vec![5, 0, 5]);
+        assert_eq!(max.get(), &[5, 5]);
+
+        max.merge(vec![4, 5].into_iter().collect());
+        assert_eq!(max.get(), &[5, 5, 5]);
+
+        max.merge(vec![6].into_iter().collect());
+        assert_eq!(max.finish(), vec![6]);
+
+        let max: AllMax<u32> = iter::empty().collect();
+        assert!(max.finish().is_empty());
+    }
+}
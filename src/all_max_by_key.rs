@@ -0,0 +1,179 @@
+use crate::common::*;
+
+/// A collection that keeps every item with the maximum key given by `F`.
+///
+/// Tied items are kept in the order they arrive, and the buffer is cleared
+/// when an item with a strictly greater key arrives. The key of the retained items
+/// is cached, so that the key function is called once per item.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "A: Serialize, F::Key: Serialize",
+        deserialize = "A: Deserialize<'de>, F::Key: Deserialize<'de>"
+    ))
+)]
+pub struct AllMaxByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    inner: Vec<A>,
+    key: Option<F::Key>,
+    _phantom: PhantomData<F>,
+}
+
+impl<A, F> AllMaxByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    pub fn get(&self) -> &[A] {
+        &self.inner
+    }
+
+    pub fn into_vec(self) -> Vec<A> {
+        self.inner
+    }
+}
+
+impl<A, F> Default for AllMaxByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn default() -> Self {
+        Self {
+            inner: Vec::new(),
+            key: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, F> From<AllMaxByKey<A, F>> for Vec<A>
+where
+    F: KeyFn<A>,
+{
+    fn from(collector: AllMaxByKey<A, F>) -> Self {
+        collector.inner
+    }
+}
+
+impl<A, F> FromIterator<A> for AllMaxByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut all = Self::default();
+        all.extend(iter);
+        all
+    }
+}
+
+impl<A, F> Extend<A> for AllMaxByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, F> Collector for AllMaxByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    type Item = A;
+    type Output = Vec<A>;
+
+    fn push(&mut self, item: A) {
+        let key = F::key(&item);
+        let ord = match &self.key {
+            Some(best) => key.cmp(best),
+            None => Ordering::Greater,
+        };
+        match ord {
+            Ordering::Less => {}
+            Ordering::Equal => self.inner.push(item),
+            Ordering::Greater => {
+                self.inner.clear();
+                self.inner.push(item);
+                self.key = Some(key);
+            }
+        }
+    }
+
+    fn merge(&mut self, mut other: Self) {
+        let ord = match (&self.key, &other.key) {
+            (Some(best), Some(key)) => key.cmp(best),
+            (_, None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+        };
+        match ord {
+            Ordering::Less => {}
+            Ordering::Equal => self.inner.append(&mut other.inner),
+            Ordering::Greater => *self = other,
+        }
+    }
+
+    fn finish(self) -> Vec<A> {
+        self.inner
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, F> FromParallelIterator<A> for AllMaxByKey<A, F>
+where
+    A: Send,
+    F: KeyFn<A> + Send,
+    F::Key: Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, F> ParallelExtend<A> for AllMaxByKey<A, F>
+where
+    A: Send,
+    F: KeyFn<A> + Send,
+    F::Key: Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy)]
+    struct ByFirst;
+
+    impl KeyFn<(usize, char)> for ByFirst {
+        type Key = usize;
+
+        fn key(item: &(usize, char)) -> usize {
+            item.0
+        }
+    }
+
+    #[test]
+    fn all_max_by_key_test() {
+        let mut all: AllMaxByKey<_, ByFirst> = vec![(1, 'a'), (3, 'b'), (2, 'c'), (3, 'd')]
+            .into_iter()
+            .collect();
+        assert_eq!(all.get(), &[(3, 'b'), (3, 'd')]);
+
+        all.extend(vec![(4, 'e'), (0, 'f')]);
+        all.merge(vec![(4, 'g'), (1, 'h')].into_iter().collect());
+        assert_eq!(all.finish(), vec![(4, 'e'), (4, 'g')]);
+    }
+}
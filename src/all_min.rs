@@ -0,0 +1,144 @@
+use crate::common::*;
+
+/// A collection that keeps every item equal to the minimum.
+///
+/// Tied items are kept in the order they arrive, and the buffer is cleared
+/// when a strictly smaller item arrives. Unlike [`MinVal`](crate::MinVal), no
+/// tied item is dropped.
+///
+/// ```rust
+/// use collected::AllMin;
+///
+/// let min: AllMin<_> = vec![4, 1, 3, 1, 2].into_iter().collect();
+/// assert_eq!(min.into_vec(), vec![1, 1]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AllMin<A> {
+    inner: Vec<A>,
+}
+
+impl<A> AllMin<A> {
+    pub fn get(&self) -> &[A] {
+        &self.inner
+    }
+
+    pub fn into_vec(self) -> Vec<A> {
+        self.inner
+    }
+}
+
+impl<A> Default for AllMin<A> {
+    fn default() -> Self {
+        Self { inner: Vec::new() }
+    }
+}
+
+impl<A> From<AllMin<A>> for Vec<A> {
+    fn from(collector: AllMin<A>) -> Self {
+        collector.inner
+    }
+}
+
+impl<A> FromIterator<A> for AllMin<A>
+where
+    A: Ord,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut all = Self::default();
+        all.extend(iter);
+        all
+    }
+}
+
+impl<A> Extend<A> for AllMin<A>
+where
+    A: Ord,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A> Collector for AllMin<A>
+where
+    A: Ord,
+{
+    type Item = A;
+    type Output = Vec<A>;
+
+    fn push(&mut self, item: A) {
+        let ord = match self.inner.first() {
+            Some(best) => best.cmp(&item),
+            None => Ordering::Equal,
+        };
+        match ord {
+            Ordering::Less => {}
+            Ordering::Equal => self.inner.push(item),
+            Ordering::Greater => {
+                self.inner.clear();
+                self.inner.push(item);
+            }
+        }
+    }
+
+    fn merge(&mut self, mut other: Self) {
+        let ord = match (self.inner.first(), other.inner.first()) {
+            (Some(best), Some(item)) => best.cmp(item),
+            _ => Ordering::Equal,
+        };
+        match ord {
+            Ordering::Less => {}
+            Ordering::Equal => self.inner.append(&mut other.inner),
+            Ordering::Greater => self.inner = other.inner,
+        }
+    }
+
+    fn finish(self) -> Vec<A> {
+        self.inner
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for AllMin<A>
+where
+    A: Ord + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for AllMin<A>
+where
+    A: Ord + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_min_test() {
+        let mut min: AllMin<_> = vec![2, 4, 2, 3].into_iter().collect();
+        assert_eq!(min.get(), &[2, 2]);
+
+        min.extend(vec![1, 5, 1]);
+        assert_eq!(min.get(), &[1, 1]);
+
+        min.merge(AllMin::default());
+        min.merge(vec![3, 1].into_iter().collect());
+        assert_eq!(min.finish(), vec![1, 1, 1]);
+    }
+}
@@ -0,0 +1,179 @@
+use crate::common::*;
+
+/// A collection that keeps every item with the minimum key given by `F`.
+///
+/// Tied items are kept in the order they arrive, and the buffer is cleared
+/// when an item with a strictly smaller key arrives. The key of the retained items
+/// is cached, so that the key function is called once per item.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "A: Serialize, F::Key: Serialize",
+        deserialize = "A: Deserialize<'de>, F::Key: Deserialize<'de>"
+    ))
+)]
+pub struct AllMinByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    inner: Vec<A>,
+    key: Option<F::Key>,
+    _phantom: PhantomData<F>,
+}
+
+impl<A, F> AllMinByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    pub fn get(&self) -> &[A] {
+        &self.inner
+    }
+
+    pub fn into_vec(self) -> Vec<A> {
+        self.inner
+    }
+}
+
+impl<A, F> Default for AllMinByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn default() -> Self {
+        Self {
+            inner: Vec::new(),
+            key: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, F> From<AllMinByKey<A, F>> for Vec<A>
+where
+    F: KeyFn<A>,
+{
+    fn from(collector: AllMinByKey<A, F>) -> Self {
+        collector.inner
+    }
+}
+
+impl<A, F> FromIterator<A> for AllMinByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut all = Self::default();
+        all.extend(iter);
+        all
+    }
+}
+
+impl<A, F> Extend<A> for AllMinByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, F> Collector for AllMinByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    type Item = A;
+    type Output = Vec<A>;
+
+    fn push(&mut self, item: A) {
+        let key = F::key(&item);
+        let ord = match &self.key {
+            Some(best) => best.cmp(&key),
+            None => Ordering::Greater,
+        };
+        match ord {
+            Ordering::Less => {}
+            Ordering::Equal => self.inner.push(item),
+            Ordering::Greater => {
+                self.inner.clear();
+                self.inner.push(item);
+                self.key = Some(key);
+            }
+        }
+    }
+
+    fn merge(&mut self, mut other: Self) {
+        let ord = match (&self.key, &other.key) {
+            (Some(best), Some(key)) => best.cmp(key),
+            (_, None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+        };
+        match ord {
+            Ordering::Less => {}
+            Ordering::Equal => self.inner.append(&mut other.inner),
+            Ordering::Greater => *self = other,
+        }
+    }
+
+    fn finish(self) -> Vec<A> {
+        self.inner
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, F> FromParallelIterator<A> for AllMinByKey<A, F>
+where
+    A: Send,
+    F: KeyFn<A> + Send,
+    F::Key: Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, F> ParallelExtend<A> for AllMinByKey<A, F>
+where
+    A: Send,
+    F: KeyFn<A> + Send,
+    F::Key: Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy)]
+    struct ByFirst;
+
+    impl KeyFn<(usize, char)> for ByFirst {
+        type Key = usize;
+
+        fn key(item: &(usize, char)) -> usize {
+            item.0
+        }
+    }
+
+    #[test]
+    fn all_min_by_key_test() {
+        let mut all: AllMinByKey<_, ByFirst> = vec![(2, 'a'), (1, 'b'), (3, 'c'), (1, 'd')]
+            .into_iter()
+            .collect();
+        assert_eq!(all.get(), &[(1, 'b'), (1, 'd')]);
+
+        all.extend(vec![(0, 'e'), (4, 'f')]);
+        all.merge(vec![(0, 'g'), (2, 'h')].into_iter().collect());
+        assert_eq!(all.finish(), vec![(0, 'e'), (0, 'g')]);
+    }
+}
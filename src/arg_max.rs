@@ -0,0 +1,190 @@
+use crate::common::*;
+
+/// A collection that computes the maximum value along with its position in the stream.
+///
+/// The position counts every item pushed into the collection, including those
+/// from later [`extend()`](Extend::extend) calls and merged collections. The
+/// type parameter `T` selects the first ([`KeepFirst`]) or the last
+/// ([`KeepLast`](crate::KeepLast)) position among equal maximums.
+///
+/// ```rust
+/// use collected::{ArgMaxVal, KeepLast};
+///
+/// let argmax: ArgMaxVal<_> = vec![3, 7, 2, 7].into_iter().collect();
+/// assert_eq!(argmax.into_inner(), Some((1, 7)));
+///
+/// let argmax: ArgMaxVal<_, KeepLast> = vec![3, 7, 2, 7].into_iter().collect();
+/// assert_eq!(argmax.into_inner(), Some((3, 7)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArgMaxVal<A, T = KeepFirst> {
+    count: usize,
+    max: Option<(usize, A)>,
+    _phantom: PhantomData<T>,
+}
+
+impl<A, T> ArgMaxVal<A, T> {
+    pub fn unwrap(self) -> (usize, A) {
+        self.max.unwrap()
+    }
+
+    pub fn get(&self) -> Option<(usize, &A)> {
+        self.max.as_ref().map(|(index, value)| (*index, value))
+    }
+
+    pub fn into_inner(self) -> Option<(usize, A)> {
+        self.max
+    }
+
+    /// Returns the number of collected items.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    fn push_indexed(&mut self, index: usize, item: A)
+    where
+        A: Ord,
+        T: TieBreak,
+    {
+        let replace = match &self.max {
+            Some((_, max)) => item > *max || (T::KEEP_LAST && item == *max),
+            None => true,
+        };
+        if replace {
+            self.max = Some((index, item));
+        }
+    }
+}
+
+impl<A, T> Default for ArgMaxVal<A, T> {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            max: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, T> From<ArgMaxVal<A, T>> for Option<(usize, A)> {
+    fn from(collector: ArgMaxVal<A, T>) -> Self {
+        collector.max
+    }
+}
+
+impl<A, T> FromIterator<A> for ArgMaxVal<A, T>
+where
+    A: Ord,
+    T: TieBreak,
+{
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        let mut argmax = Self::default();
+        argmax.extend(iter);
+        argmax
+    }
+}
+
+impl<A, T> Extend<A> for ArgMaxVal<A, T>
+where
+    A: Ord,
+    T: TieBreak,
+{
+    fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, T> Collector for ArgMaxVal<A, T>
+where
+    A: Ord,
+    T: TieBreak,
+{
+    type Item = A;
+    type Output = Option<(usize, A)>;
+
+    fn push(&mut self, item: A) {
+        let index = self.count;
+        self.count += 1;
+        self.push_indexed(index, item);
+    }
+
+    fn merge(&mut self, other: Self) {
+        if let Some((index, item)) = other.max {
+            self.push_indexed(self.count + index, item);
+        }
+        self.count += other.count;
+    }
+
+    fn finish(self) -> Option<(usize, A)> {
+        self.max
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, T> FromParallelIterator<A> for ArgMaxVal<A, T>
+where
+    A: Ord + Send,
+    T: TieBreak + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, T> ParallelExtend<A> for ArgMaxVal<A, T>
+where
+    A: Ord + Send,
+    T: TieBreak + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeepLast;
+
+    #[test]
+    fn arg_max_test() {
+        let mut argmax: ArgMaxVal<usize> = vec![3, 9, 4, 9].into_iter().collect();
+        assert_eq!(argmax.get(), Some((1, &9)));
+
+        argmax.extend(vec![1, 10, 10]);
+        assert_eq!(argmax.count(), 7);
+        assert_eq!(argmax.unwrap(), (5, 10));
+
+        let mut argmax: ArgMaxVal<usize, KeepLast> = vec![3, 9, 4, 9].into_iter().collect();
+        assert_eq!(argmax.get(), Some((3, &9)));
+
+        argmax.extend(vec![1, 9]);
+        assert_eq!(argmax.unwrap(), (5, 9));
+
+        let argmax: ArgMaxVal<usize> = iter::empty().collect();
+        assert_eq!(argmax.into_inner(), None);
+    }
+
+    #[test]
+    fn arg_max_merge_test() {
+        let mut lhs: ArgMaxVal<usize> = vec![5, 2].into_iter().collect();
+        lhs.merge(vec![1, 5, 0].into_iter().collect());
+        assert_eq!(lhs.get(), Some((0, &5)));
+
+        lhs.merge(ArgMaxVal::default());
+        lhs.merge(vec![6].into_iter().collect());
+        assert_eq!(lhs.finish(), Some((5, 6)));
+
+        let mut lhs: ArgMaxVal<usize, KeepLast> = vec![5, 2].into_iter().collect();
+        lhs.merge(vec![1, 5, 0].into_iter().collect());
+        assert_eq!(lhs.finish(), Some((3, 5)));
+    }
+}
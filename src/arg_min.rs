@@ -0,0 +1,190 @@
+use crate::common::*;
+
+/// A collection that computes the minimum value along with its position in the stream.
+///
+/// The position counts every item pushed into the collection, including those
+/// from later [`extend()`](Extend::extend) calls and merged collections. The
+/// type parameter `T` selects the first ([`KeepFirst`]) or the last
+/// ([`KeepLast`](crate::KeepLast)) position among equal minimums.
+///
+/// ```rust
+/// use collected::{ArgMinVal, KeepLast};
+///
+/// let argmin: ArgMinVal<_> = vec![3, 1, 2, 1].into_iter().collect();
+/// assert_eq!(argmin.into_inner(), Some((1, 1)));
+///
+/// let argmin: ArgMinVal<_, KeepLast> = vec![3, 1, 2, 1].into_iter().collect();
+/// assert_eq!(argmin.into_inner(), Some((3, 1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArgMinVal<A, T = KeepFirst> {
+    count: usize,
+    min: Option<(usize, A)>,
+    _phantom: PhantomData<T>,
+}
+
+impl<A, T> ArgMinVal<A, T> {
+    pub fn unwrap(self) -> (usize, A) {
+        self.min.unwrap()
+    }
+
+    pub fn get(&self) -> Option<(usize, &A)> {
+        self.min.as_ref().map(|(index, value)| (*index, value))
+    }
+
+    pub fn into_inner(self) -> Option<(usize, A)> {
+        self.min
+    }
+
+    /// Returns the number of collected items.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    fn push_indexed(&mut self, index: usize, item: A)
+    where
+        A: Ord,
+        T: TieBreak,
+    {
+        let replace = match &self.min {
+            Some((_, min)) => item < *min || (T::KEEP_LAST && item == *min),
+            None => true,
+        };
+        if replace {
+            self.min = Some((index, item));
+        }
+    }
+}
+
+impl<A, T> Default for ArgMinVal<A, T> {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            min: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, T> From<ArgMinVal<A, T>> for Option<(usize, A)> {
+    fn from(collector: ArgMinVal<A, T>) -> Self {
+        collector.min
+    }
+}
+
+impl<A, T> FromIterator<A> for ArgMinVal<A, T>
+where
+    A: Ord,
+    T: TieBreak,
+{
+    fn from_iter<I: IntoIterator<Item = A>>(iter: I) -> Self {
+        let mut argmin = Self::default();
+        argmin.extend(iter);
+        argmin
+    }
+}
+
+impl<A, T> Extend<A> for ArgMinVal<A, T>
+where
+    A: Ord,
+    T: TieBreak,
+{
+    fn extend<I: IntoIterator<Item = A>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, T> Collector for ArgMinVal<A, T>
+where
+    A: Ord,
+    T: TieBreak,
+{
+    type Item = A;
+    type Output = Option<(usize, A)>;
+
+    fn push(&mut self, item: A) {
+        let index = self.count;
+        self.count += 1;
+        self.push_indexed(index, item);
+    }
+
+    fn merge(&mut self, other: Self) {
+        if let Some((index, item)) = other.min {
+            self.push_indexed(self.count + index, item);
+        }
+        self.count += other.count;
+    }
+
+    fn finish(self) -> Option<(usize, A)> {
+        self.min
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, T> FromParallelIterator<A> for ArgMinVal<A, T>
+where
+    A: Ord + Send,
+    T: TieBreak + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, T> ParallelExtend<A> for ArgMinVal<A, T>
+where
+    A: Ord + Send,
+    T: TieBreak + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeepLast;
+
+    #[test]
+    fn arg_min_test() {
+        let mut argmin: ArgMinVal<usize> = vec![3, 1, 4, 1].into_iter().collect();
+        assert_eq!(argmin.get(), Some((1, &1)));
+
+        argmin.extend(vec![5, 0, 0]);
+        assert_eq!(argmin.count(), 7);
+        assert_eq!(argmin.unwrap(), (5, 0));
+
+        let mut argmin: ArgMinVal<usize, KeepLast> = vec![3, 1, 4, 1].into_iter().collect();
+        assert_eq!(argmin.get(), Some((3, &1)));
+
+        argmin.extend(vec![9, 1]);
+        assert_eq!(argmin.unwrap(), (5, 1));
+
+        let argmin: ArgMinVal<usize> = iter::empty().collect();
+        assert_eq!(argmin.into_inner(), None);
+    }
+
+    #[test]
+    fn arg_min_merge_test() {
+        let mut lhs: ArgMinVal<usize> = vec![2, 5].into_iter().collect();
+        lhs.merge(vec![7, 2, 9].into_iter().collect());
+        assert_eq!(lhs.get(), Some((0, &2)));
+
+        lhs.merge(ArgMinVal::default());
+        lhs.merge(vec![1].into_iter().collect());
+        assert_eq!(lhs.finish(), Some((5, 1)));
+
+        let mut lhs: ArgMinVal<usize, KeepLast> = vec![2, 5].into_iter().collect();
+        lhs.merge(vec![7, 2, 9].into_iter().collect());
+        assert_eq!(lhs.finish(), Some((3, 2)));
+    }
+}
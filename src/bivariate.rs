@@ -0,0 +1,236 @@
+use crate::common::*;
+
+/// A collection that computes the covariance, the correlation and the linear
+/// regression of `(x, y)` pairs of integer or float values.
+///
+/// It keeps the count, the means, and the sums of squared deviations and the
+/// co-moment, updated in one pass as Welford's algorithm does. Partial states
+/// are merged by Chan's parallel formula.
+///
+/// ```rust
+/// use collected::Bivariate;
+///
+/// let stats: Bivariate<f64> = vec![(1.0, 3.0), (2.0, 5.0), (3.0, 7.0)].into_iter().collect();
+/// assert_eq!(stats.slope(), Some(2.0));
+/// assert_eq!(stats.intercept(), Some(1.0));
+/// assert_eq!(stats.r_squared(), Some(1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Bivariate<X, Y = X> {
+    count: u64,
+    mean_x: f64,
+    mean_y: f64,
+    m2_x: f64,
+    m2_y: f64,
+    co_moment: f64,
+    _phantom: PhantomData<(X, Y)>,
+}
+
+impl<X, Y> Bivariate<X, Y> {
+    /// Returns the number of collected pairs.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the means of `x` and `y`, or `None` if no pairs are collected.
+    pub fn mean(&self) -> Option<(f64, f64)> {
+        (self.count > 0).then_some((self.mean_x, self.mean_y))
+    }
+
+    /// Returns the population covariance, or `None` if no pairs are collected.
+    pub fn population_covariance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.co_moment / self.count as f64)
+    }
+
+    /// Returns the sample covariance, or `None` if less than two pairs are collected.
+    pub fn sample_covariance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.co_moment / (self.count - 1) as f64)
+    }
+
+    /// Returns the Pearson correlation coefficient, or `None` if either `x` or
+    /// `y` has zero variance.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn correlation(&self) -> Option<f64> {
+        (self.m2_x > 0.0 && self.m2_y > 0.0)
+            .then(|| self.co_moment / math::sqrt(self.m2_x * self.m2_y))
+    }
+
+    /// Returns the least-squares slope of `y` on `x`, or `None` if `x` has zero variance.
+    pub fn slope(&self) -> Option<f64> {
+        (self.m2_x > 0.0).then(|| self.co_moment / self.m2_x)
+    }
+
+    /// Returns the least-squares intercept of `y` on `x`, or `None` if `x` has zero variance.
+    pub fn intercept(&self) -> Option<f64> {
+        self.slope().map(|slope| self.mean_y - slope * self.mean_x)
+    }
+
+    /// Returns the coefficient of determination R² of the least-squares line, or
+    /// `None` if either `x` or `y` has zero variance.
+    pub fn r_squared(&self) -> Option<f64> {
+        (self.m2_x > 0.0 && self.m2_y > 0.0)
+            .then(|| self.co_moment * self.co_moment / (self.m2_x * self.m2_y))
+    }
+}
+
+impl<X, Y> Default for Bivariate<X, Y> {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            mean_x: 0.0,
+            mean_y: 0.0,
+            m2_x: 0.0,
+            m2_y: 0.0,
+            co_moment: 0.0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<X, Y> FromIterator<(X, Y)> for Bivariate<X, Y>
+where
+    X: ToF64,
+    Y: ToF64,
+{
+    fn from_iter<T: IntoIterator<Item = (X, Y)>>(iter: T) -> Self {
+        let mut stats = Self::default();
+        stats.extend(iter);
+        stats
+    }
+}
+
+impl<X, Y> Extend<(X, Y)> for Bivariate<X, Y>
+where
+    X: ToF64,
+    Y: ToF64,
+{
+    fn extend<T: IntoIterator<Item = (X, Y)>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<X, Y> Collector for Bivariate<X, Y>
+where
+    X: ToF64,
+    Y: ToF64,
+{
+    type Item = (X, Y);
+    type Output = Self;
+
+    fn push(&mut self, (x, y): (X, Y)) {
+        let (x, y) = (x.to_f64(), y.to_f64());
+        self.count += 1;
+        let n = self.count as f64;
+
+        let delta_x = x - self.mean_x;
+        let delta_y = y - self.mean_y;
+        self.mean_x += delta_x / n;
+        self.mean_y += delta_y / n;
+        self.m2_x += delta_x * (x - self.mean_x);
+        self.m2_y += delta_y * (y - self.mean_y);
+        self.co_moment += delta_x * (y - self.mean_y);
+    }
+
+    fn merge(&mut self, other: Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other;
+            return;
+        }
+
+        let count = self.count + other.count;
+        let delta_x = other.mean_x - self.mean_x;
+        let delta_y = other.mean_y - self.mean_y;
+        let weight = other.count as f64 / count as f64;
+        let scale = self.count as f64 * weight;
+
+        self.mean_x += delta_x * weight;
+        self.mean_y += delta_y * weight;
+        self.m2_x += other.m2_x + delta_x * delta_x * scale;
+        self.m2_y += other.m2_y + delta_y * delta_y * scale;
+        self.co_moment += other.co_moment + delta_x * delta_y * scale;
+        self.count = count;
+    }
+
+    fn finish(self) -> Self {
+        self
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<X, Y> FromParallelIterator<(X, Y)> for Bivariate<X, Y>
+where
+    X: ToF64 + Send,
+    Y: ToF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = (X, Y)>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<X, Y> ParallelExtend<(X, Y)> for Bivariate<X, Y>
+where
+    X: ToF64 + Send,
+    Y: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = (X, Y)>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn bivariate_test() {
+        let mut stats: Bivariate<i32, f64> = vec![(1, 2.0), (2, 4.0)].into_iter().collect();
+        stats.extend(vec![(3, 5.0), (4, 4.0), (5, 5.0)]);
+
+        assert_eq!(stats.count(), 5);
+        assert_eq!(stats.mean(), Some((3.0, 4.0)));
+        assert_close(stats.population_covariance().unwrap(), 1.2);
+        assert_close(stats.sample_covariance().unwrap(), 1.5);
+        assert_close(stats.slope().unwrap(), 0.6);
+        assert_close(stats.intercept().unwrap(), 2.2);
+        assert_close(stats.r_squared().unwrap(), 0.6);
+        #[cfg(any(feature = "std", feature = "libm"))]
+        assert_close(stats.correlation().unwrap(), 0.6f64.sqrt());
+
+        let stats: Bivariate<f64> = vec![(1.0, 2.0), (1.0, 3.0)].into_iter().collect();
+        assert_eq!(stats.slope(), None);
+        assert_eq!(stats.r_squared(), None);
+    }
+
+    #[test]
+    fn bivariate_merge_test() {
+        let pairs: Vec<(f64, f64)> = (0..100)
+            .map(|val| (val as f64, ((val * 17) % 23) as f64 + val as f64 * 0.3))
+            .collect();
+        let whole: Bivariate<f64> = pairs.iter().copied().collect();
+
+        let mut merged: Bivariate<f64> = pairs[..40].iter().copied().collect();
+        merged.merge(pairs[40..].iter().copied().collect());
+        merged.merge(Bivariate::default());
+
+        assert_eq!(merged.count(), whole.count());
+        assert_close(
+            merged.sample_covariance().unwrap(),
+            whole.sample_covariance().unwrap(),
+        );
+        assert_close(merged.slope().unwrap(), whole.slope().unwrap());
+        assert_close(merged.intercept().unwrap(), whole.intercept().unwrap());
+        assert_close(merged.r_squared().unwrap(), whole.r_squared().unwrap());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::common::*;
+
+/// A collection that multiplies integers, and stops at the first overflow.
+///
+/// Once an overflow occurs, the collection keeps the [`OverflowError`] with the
+/// position of the overflowing item, and ignores the subsequent items.
+///
+/// Unlike other collections, it does not implement [`Collector`], since an
+/// overflow inside a partial result cannot be located when merging it with
+/// another. For the same reason it does not support rayon. Items are added by
+/// [`push()`](CheckedProduct::push) or [`extend()`](Extend::extend).
+///
+/// ```rust
+/// use collected::{CheckedProduct, OverflowError};
+///
+/// let product: CheckedProduct<u8> = vec![2, 8, 16, 0].into_iter().collect();
+/// assert_eq!(product.into_inner(), Err(OverflowError { index: 2 }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CheckedProduct<A> {
+    product: Result<A, OverflowError>,
+    count: usize,
+}
+
+impl<A> CheckedProduct<A> {
+    pub fn get(&self) -> Result<&A, OverflowError> {
+        self.product.as_ref().map_err(|err| *err)
+    }
+
+    pub fn into_inner(self) -> Result<A, OverflowError> {
+        self.product
+    }
+
+    /// Returns the number of collected items, including those after the overflow.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<A> Default for CheckedProduct<A>
+where
+    A: Integer,
+{
+    fn default() -> Self {
+        Self {
+            product: Ok(A::ONE),
+            count: 0,
+        }
+    }
+}
+
+impl<A> CheckedProduct<A>
+where
+    A: Integer,
+{
+    /// Feeds one item, which is ignored after an overflow.
+    pub fn push(&mut self, item: A) {
+        let index = self.count;
+        self.count += 1;
+
+        if let Ok(product) = self.product {
+            self.product = product.checked_mul(item).ok_or(OverflowError { index });
+        }
+    }
+}
+
+impl<A> From<CheckedProduct<A>> for Result<A, OverflowError> {
+    fn from(collector: CheckedProduct<A>) -> Self {
+        collector.product
+    }
+}
+
+impl<A> FromIterator<A> for CheckedProduct<A>
+where
+    A: Integer,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut product = Self::default();
+        product.extend(iter);
+        product
+    }
+}
+
+impl<A> Extend<A> for CheckedProduct<A>
+where
+    A: Integer,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_product_test() {
+        let mut product: CheckedProduct<i32> = vec![1, 2, 3].into_iter().collect();
+        assert_eq!(product.get(), Ok(&6));
+
+        product.extend(vec![i32::MAX, 0]);
+        assert_eq!(product.get(), Err(OverflowError { index: 3 }));
+        assert_eq!(product.count(), 5);
+
+        let mut product: CheckedProduct<u8> = vec![200, 2].into_iter().collect();
+        product.push(0);
+        assert_eq!(product.into_inner(), Err(OverflowError { index: 1 }));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::common::*;
+
+/// A collection that sums up integers, and stops at the first overflow.
+///
+/// Once an overflow occurs, the collection keeps the [`OverflowError`] with the
+/// position of the overflowing item, and ignores the subsequent items.
+///
+/// Unlike other collections, it does not implement [`Collector`], since an
+/// overflow inside a partial result cannot be located when merging it with
+/// another. For the same reason it does not support rayon. Items are added by
+/// [`push()`](CheckedSum::push) or [`extend()`](Extend::extend).
+///
+/// ```rust
+/// use collected::{CheckedSum, OverflowError};
+///
+/// let sum: CheckedSum<u8> = vec![100, 100, 100, 1].into_iter().collect();
+/// assert_eq!(sum.into_inner(), Err(OverflowError { index: 2 }));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CheckedSum<A> {
+    sum: Result<A, OverflowError>,
+    count: usize,
+}
+
+impl<A> CheckedSum<A> {
+    pub fn get(&self) -> Result<&A, OverflowError> {
+        self.sum.as_ref().map_err(|err| *err)
+    }
+
+    pub fn into_inner(self) -> Result<A, OverflowError> {
+        self.sum
+    }
+
+    /// Returns the number of collected items, including those after the overflow.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<A> Default for CheckedSum<A>
+where
+    A: Integer,
+{
+    fn default() -> Self {
+        Self {
+            sum: Ok(A::ZERO),
+            count: 0,
+        }
+    }
+}
+
+impl<A> CheckedSum<A>
+where
+    A: Integer,
+{
+    /// Feeds one item, which is ignored after an overflow.
+    pub fn push(&mut self, item: A) {
+        let index = self.count;
+        self.count += 1;
+
+        if let Ok(sum) = self.sum {
+            self.sum = sum.checked_add(item).ok_or(OverflowError { index });
+        }
+    }
+}
+
+impl<A> From<CheckedSum<A>> for Result<A, OverflowError> {
+    fn from(collector: CheckedSum<A>) -> Self {
+        collector.sum
+    }
+}
+
+impl<A> FromIterator<A> for CheckedSum<A>
+where
+    A: Integer,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut sum = Self::default();
+        sum.extend(iter);
+        sum
+    }
+}
+
+impl<A> Extend<A> for CheckedSum<A>
+where
+    A: Integer,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_sum_test() {
+        let mut sum: CheckedSum<i32> = vec![1, 2, 3].into_iter().collect();
+        assert_eq!(sum.get(), Ok(&6));
+
+        sum.extend(vec![i32::MAX, -10]);
+        assert_eq!(sum.get(), Err(OverflowError { index: 3 }));
+        assert_eq!(sum.count(), 5);
+
+        let mut sum: CheckedSum<i8> = vec![100, 100].into_iter().collect();
+        sum.push(-100);
+        assert_eq!(sum.into_inner(), Err(OverflowError { index: 1 }));
+    }
+}
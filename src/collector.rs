@@ -0,0 +1,228 @@
+/// The common interface of collection types in the crate.
+///
+/// A collector accepts items one at a time by [`push()`](Collector::push),
+/// absorbs the state of another collector of the same type by
+/// [`merge()`](Collector::merge), and produces the final result by
+/// [`finish()`](Collector::finish).
+///
+/// Merging is order-aware. The `other` collector is treated as if its
+/// items were pushed after the items of `self`.
+///
+/// ```rust
+/// use collected::{Collector, MaxVal, SumVal};
+///
+/// fn collect_into<C: Collector + Default>(items: Vec<C::Item>) -> C::Output {
+///     let mut collector = C::default();
+///     items.into_iter().for_each(|item| collector.push(item));
+///     collector.finish()
+/// }
+///
+/// assert_eq!(collect_into::<SumVal<usize>>(vec![1, 2, 3]), 6);
+/// assert_eq!(collect_into::<MaxVal<usize>>(vec![1, 3, 2]), Some(3));
+/// ```
+pub trait Collector {
+    /// The type of items accepted by the collector.
+    type Item;
+
+    /// The type of the final result.
+    type Output;
+
+    /// Feeds one item into the collector.
+    fn push(&mut self, item: Self::Item);
+
+    /// Absorbs another collector as if its items were pushed after those of `self`.
+    fn merge(&mut self, other: Self);
+
+    /// Consumes the collector and returns the final result.
+    fn finish(self) -> Self::Output;
+
+    /// Returns `true` if the collector is saturated, that is, further items would
+    /// not change its output.
+    fn is_done(&self) -> bool {
+        false
+    }
+
+    /// Pushes items from an iterator, and stops pulling items once the collector is saturated.
+    fn push_until_done<I>(&mut self, iter: I)
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Self::Item>,
+    {
+        let mut iter = iter.into_iter();
+        while !self.is_done() {
+            match iter.next() {
+                Some(item) => self.push(item),
+                None => break,
+            }
+        }
+    }
+}
+
+/// An extension trait that collects iterators into [`Collector`]s.
+///
+/// Unlike [`collect()`](Iterator::collect), it stops pulling items once the
+/// collector is saturated.
+///
+/// ```rust
+/// use collected::{CollectedExt, First};
+///
+/// let mut iter = 1..;
+/// let first: First<_> = iter.by_ref().collected();
+/// assert_eq!(first.into_inner(), Some(1));
+/// assert_eq!(iter.next(), Some(2));
+/// ```
+pub trait CollectedExt: Iterator {
+    /// Collects items into a collector until the collector is saturated.
+    fn collected<C>(self) -> C
+    where
+        Self: Sized,
+        C: Collector<Item = Self::Item> + Default,
+    {
+        let mut collector = C::default();
+        collector.push_until_done(self);
+        collector
+    }
+}
+
+impl<I> CollectedExt for I where I: Iterator {}
+
+macro_rules! impl_tuple_collector {
+    ($($ty:ident $index:tt),+) => {
+        /// Collects tuples by pushing each element into the corresponding collector,
+        /// in the manner of [`unzip()`](Iterator::unzip).
+        ///
+        /// The tuple is saturated once every collector is saturated.
+        impl<$($ty),+> Collector for ($($ty,)+)
+        where
+            $($ty: Collector,)+
+        {
+            type Item = ($($ty::Item,)+);
+            type Output = ($($ty::Output,)+);
+
+            fn push(&mut self, item: Self::Item) {
+                $(self.$index.push(item.$index);)+
+            }
+
+            fn merge(&mut self, other: Self) {
+                $(self.$index.merge(other.$index);)+
+            }
+
+            fn finish(self) -> Self::Output {
+                ($(self.$index.finish(),)+)
+            }
+
+            fn is_done(&self) -> bool {
+                $(self.$index.is_done())&&+
+            }
+        }
+    };
+}
+
+impl_tuple_collector!(C1 0);
+impl_tuple_collector!(C1 0, C2 1);
+impl_tuple_collector!(C1 0, C2 1, C3 2);
+impl_tuple_collector!(C1 0, C2 1, C3 2, C4 3);
+impl_tuple_collector!(C1 0, C2 1, C3 2, C4 3, C5 4);
+impl_tuple_collector!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5);
+impl_tuple_collector!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5, C7 6);
+impl_tuple_collector!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5, C7 6, C8 7);
+impl_tuple_collector!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5, C7 6, C8 7, C9 8);
+impl_tuple_collector!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5, C7 6, C8 7, C9 8, C10 9);
+impl_tuple_collector!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5, C7 6, C8 7, C9 8, C10 9, C11 10);
+impl_tuple_collector!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5, C7 6, C8 7, C9 8, C10 9, C11 10, C12 11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Count, First, Last, LastN, MaxVal, SumVal, Tee, TopK};
+
+    fn collect_chunks<C>(chunks: Vec<Vec<C::Item>>) -> C::Output
+    where
+        C: Collector + Default,
+    {
+        chunks
+            .into_iter()
+            .map(|chunk| {
+                let mut collector = C::default();
+                chunk.into_iter().for_each(|item| collector.push(item));
+                collector
+            })
+            .reduce(|mut lhs, rhs| {
+                lhs.merge(rhs);
+                lhs
+            })
+            .unwrap_or_default()
+            .finish()
+    }
+
+    #[test]
+    fn collector_test() {
+        let chunks = || vec![vec![3, 1, 4], vec![], vec![1, 5, 9, 2], vec![6]];
+
+        assert_eq!(collect_chunks::<SumVal<usize>>(chunks()), 31);
+        assert_eq!(collect_chunks::<MaxVal<usize>>(chunks()), Some(9));
+        assert_eq!(collect_chunks::<Count<usize>>(chunks()), 8);
+        assert_eq!(collect_chunks::<First<usize>>(chunks()), Some(3));
+        assert_eq!(collect_chunks::<Last<usize>>(chunks()), Some(6));
+        assert_eq!(collect_chunks::<TopK<usize, 3>>(chunks()), vec![9, 6, 5]);
+        assert_eq!(
+            collect_chunks::<LastN<Vec<_>, usize, 3>>(chunks()),
+            vec![9, 2, 6]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn collector_hash_test() {
+        use crate::{GroupHashMap, UniqueHashSet};
+        use maplit::{hashmap, hashset};
+
+        assert_eq!(
+            collect_chunks::<UniqueHashSet<usize>>(vec![vec![1, 2], vec![2]]),
+            None
+        );
+        assert_eq!(
+            collect_chunks::<UniqueHashSet<usize>>(vec![vec![1, 2], vec![3]]),
+            Some(hashset! {1, 2, 3})
+        );
+        assert_eq!(
+            collect_chunks::<GroupHashMap<char, usize>>(vec![
+                vec![('a', 1), ('b', 2)],
+                vec![('a', 3)]
+            ]),
+            hashmap! {
+                'a' => vec![1, 3],
+                'b' => vec![2],
+            }
+        );
+    }
+
+    #[test]
+    fn collected_test() {
+        let mut pulled = 0;
+        let first: First<_> = (0..10).inspect(|_| pulled += 1).collected();
+        assert_eq!(first.into_inner(), Some(0));
+        assert_eq!(pulled, 1);
+
+        let mut pulled = 0;
+        let (first, last): (First<_>, Last<_>) = (0..10)
+            .map(|val| (val, val))
+            .inspect(|_| pulled += 1)
+            .collected();
+        assert_eq!((first.finish(), last.finish()), (Some(0), Some(9)));
+        assert_eq!(pulled, 10);
+
+        let mut pulled = 0;
+        let (lhs, rhs): (First<_>, First<_>) = (0..10)
+            .map(|val| (val, val))
+            .inspect(|_| pulled += 1)
+            .collected();
+        assert_eq!((lhs.finish(), rhs.finish()), (Some(0), Some(0)));
+        assert_eq!(pulled, 1);
+
+        let mut pulled = 0;
+        let tee: Tee<(First<_>, First<_>)> = (0..10).inspect(|_| pulled += 1).collected();
+        assert_eq!(tee.finish(), (Some(0), Some(0)));
+        assert_eq!(pulled, 1);
+    }
+}
@@ -1,12 +1,34 @@
-pub use itertools::Itertools;
-pub use min_max_heap::MinMaxHeap;
-pub use std::{
-    borrow::Borrow,
-    cmp::Reverse,
-    collections::{hash_map, BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque},
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) use crate::num::math;
+#[cfg(feature = "rayon")]
+pub(crate) use crate::par;
+pub use crate::{
+    collector::Collector,
+    error::{BinsMismatchError, NegativeWeightError, NonPositiveError, OverflowError},
+    key_fn::{CompareFn, KeyFn},
+    nan_policy::{IgnoreNan, NanPolicy},
+    num::{ExactF64, Float, Integer, ToF64},
+    tie_break::{KeepFirst, TieBreak},
+};
+pub use alloc::{
+    collections::{BTreeSet, BinaryHeap, VecDeque},
+    vec::Vec,
+};
+#[cfg(feature = "unstable")]
+pub use core::mem::MaybeUninit;
+pub use core::{
+    cmp::{self, Ordering, Reverse},
     hash::Hash,
     iter::{self, Extend, FromIterator, Product, Sum},
     marker::PhantomData,
-    mem::{self, MaybeUninit},
+    mem,
     ops::{Add, Mul},
 };
+#[cfg(all(not(feature = "std"), feature = "hashbrown"))]
+pub use hashbrown::{hash_map, HashMap, HashSet};
+#[cfg(feature = "rayon")]
+pub use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelExtend};
+#[cfg(feature = "serde")]
+pub use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+pub use std::collections::{hash_map, HashMap, HashSet};
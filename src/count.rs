@@ -2,6 +2,7 @@ use crate::common::*;
 
 /// A counter that counts witnessed items when collected or extended from an iterator.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Count<T> {
     count: usize,
     _phantom: PhantomData<T>,
@@ -37,6 +38,49 @@ impl<A> Extend<A> for Count<A> {
     }
 }
 
+impl<A> Collector for Count<A> {
+    type Item = A;
+    type Output = usize;
+
+    fn push(&mut self, _item: A) {
+        self.count += 1;
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.count += other.count;
+    }
+
+    fn finish(self) -> usize {
+        self.count
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for Count<A>
+where
+    A: Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for Count<A>
+where
+    A: Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,70 @@
+use core::fmt;
+
+/// The error returned by [`CheckedSum`](crate::CheckedSum) and
+/// [`CheckedProduct`](crate::CheckedProduct) when the result overflows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OverflowError {
+    /// The position of the item at which the overflow is detected.
+    pub index: usize,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "arithmetic overflow at item {}", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OverflowError {}
+
+/// The error returned by [`Histogram::try_merge()`](crate::Histogram::try_merge)
+/// when the histograms have different bins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BinsMismatchError;
+
+impl fmt::Display for BinsMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the histograms have different bins")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BinsMismatchError {}
+
+/// The error returned by collections such as [`GeometricMean`](crate::GeometricMean)
+/// when a value is zero, negative or NaN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NonPositiveError {
+    /// The position of the first item that is not positive.
+    pub index: usize,
+}
+
+impl fmt::Display for NonPositiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "non-positive value at item {}", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NonPositiveError {}
+
+/// The error returned by collections such as [`WeightedMean`](crate::WeightedMean)
+/// when a weight is negative or NaN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NegativeWeightError {
+    /// The position of the first item with an invalid weight.
+    pub index: usize,
+}
+
+impl fmt::Display for NegativeWeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "negative weight at item {}", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NegativeWeightError {}
@@ -0,0 +1,231 @@
+use crate::common::*;
+
+/// A collection that computes the exponentially weighted moving mean and variance.
+///
+/// The smoothing factor is the rational number `alpha = NUM / DEN` within
+/// `(0, 1]`. The `i`-th latest value is weighted by `alpha * (1 - alpha)^i`,
+/// and the statistics are normalized by the sum of weights, which corrects the
+/// bias of the first samples. The mean agrees with [`Ewma::get()`](crate::Ewma::get).
+///
+/// It keeps the sums of weights and squared weights, the mean and the weighted
+/// sum of squared deviations, which decay by each pushed value. Merging is
+/// exact, where the merged collection holds the later values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EwmVariance<A, const NUM: u64, const DEN: u64> {
+    weight_sum: f64,
+    weight_sq_sum: f64,
+    mean: f64,
+    m2: f64,
+    decay: f64,
+    count: u64,
+    _phantom: PhantomData<A>,
+}
+
+impl<A, const NUM: u64, const DEN: u64> EwmVariance<A, NUM, DEN> {
+    /// The smoothing factor `NUM / DEN`.
+    pub const ALPHA: f64 = {
+        assert!(
+            NUM > 0 && NUM <= DEN,
+            "the smoothing factor must be within (0, 1]"
+        );
+        NUM as f64 / DEN as f64
+    };
+
+    /// Returns the number of collected values.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the bias-corrected moving mean, or `None` if no values are collected.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the weighted population variance, or `None` if no values are collected.
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.weight_sum)
+    }
+
+    /// Returns the unbiased variance for reliability weights, or `None` if less
+    /// than two values are collected.
+    pub fn unbiased_variance(&self) -> Option<f64> {
+        let denom = self.weight_sum - self.weight_sq_sum / self.weight_sum;
+        (self.count > 1 && denom > 0.0).then(|| self.m2 / denom)
+    }
+
+    /// Returns the standard deviation of the population variance, or `None` if
+    /// no values are collected.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(math::sqrt)
+    }
+
+    /// Scales the weights of the collected values by `decay`.
+    fn scale(&mut self, decay: f64) {
+        self.weight_sum *= decay;
+        self.weight_sq_sum *= decay * decay;
+        self.m2 *= decay;
+        self.decay *= decay;
+    }
+}
+
+impl<A, const NUM: u64, const DEN: u64> Default for EwmVariance<A, NUM, DEN> {
+    fn default() -> Self {
+        Self {
+            weight_sum: 0.0,
+            weight_sq_sum: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            decay: 1.0,
+            count: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, const NUM: u64, const DEN: u64> FromIterator<A> for EwmVariance<A, NUM, DEN>
+where
+    A: ToF64,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut var = Self::default();
+        var.extend(iter);
+        var
+    }
+}
+
+impl<A, const NUM: u64, const DEN: u64> Extend<A> for EwmVariance<A, NUM, DEN>
+where
+    A: ToF64,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, const NUM: u64, const DEN: u64> Collector for EwmVariance<A, NUM, DEN>
+where
+    A: ToF64,
+{
+    type Item = A;
+    type Output = Self;
+
+    fn push(&mut self, item: A) {
+        let alpha = Self::ALPHA;
+        let value = item.to_f64();
+        self.scale(1.0 - alpha);
+        self.count += 1;
+
+        self.weight_sum += alpha;
+        self.weight_sq_sum += alpha * alpha;
+        let delta = value - self.mean;
+        self.mean += delta * alpha / self.weight_sum;
+        self.m2 += alpha * delta * (value - self.mean);
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.scale(other.decay);
+        self.count += other.count;
+
+        let weight_sum = self.weight_sum + other.weight_sum;
+        if weight_sum > 0.0 {
+            let delta = other.mean - self.mean;
+            let ratio = other.weight_sum / weight_sum;
+            self.mean += delta * ratio;
+            self.m2 += other.m2 + delta * delta * self.weight_sum * ratio;
+        }
+        self.weight_sum = weight_sum;
+        self.weight_sq_sum += other.weight_sq_sum;
+    }
+
+    fn finish(self) -> Self {
+        self
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const NUM: u64, const DEN: u64> FromParallelIterator<A> for EwmVariance<A, NUM, DEN>
+where
+    A: ToF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const NUM: u64, const DEN: u64> ParallelExtend<A> for EwmVariance<A, NUM, DEN>
+where
+    A: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_util::assert_close, Ewma, WeightKind, WeightedVariance};
+
+    #[test]
+    fn ewm_variance_test() {
+        let values = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0];
+        let mut var: EwmVariance<f64, 1, 4> = values[..3].iter().copied().collect();
+        var.extend(values[3..].iter().copied());
+
+        let ewma: Ewma<f64, 1, 4> = values.iter().copied().collect();
+        let expect: WeightedVariance<f64> = values
+            .iter()
+            .rev()
+            .scan(0.25, |weight, &value| {
+                let item = (value, *weight);
+                *weight *= 0.75;
+                Some(item)
+            })
+            .collect();
+
+        assert_eq!(var.count(), 8);
+        assert_close(var.mean().unwrap(), ewma.get().unwrap());
+        assert_close(
+            var.variance().unwrap(),
+            expect.population_variance().unwrap().unwrap(),
+        );
+        assert_close(
+            var.unbiased_variance().unwrap(),
+            expect
+                .sample_variance(WeightKind::Reliability)
+                .unwrap()
+                .unwrap(),
+        );
+
+        let var: EwmVariance<f64, 1, 4> = iter::once(2.0).collect();
+        assert_eq!((var.mean(), var.variance()), (Some(2.0), Some(0.0)));
+        assert_eq!(var.unbiased_variance(), None);
+    }
+
+    #[test]
+    fn ewm_variance_merge_test() {
+        let values: Vec<f64> = (0..60).map(|val| ((val * 5) % 13) as f64).collect();
+        let whole: EwmVariance<f64, 1, 8> = values.iter().copied().collect();
+
+        let mut merged: EwmVariance<f64, 1, 8> = EwmVariance::default();
+        merged.merge(values[..25].iter().copied().collect());
+        merged.merge(values[25..].iter().copied().collect());
+
+        assert_eq!(merged.count(), 60);
+        assert_close(merged.mean().unwrap(), whole.mean().unwrap());
+        assert_close(merged.variance().unwrap(), whole.variance().unwrap());
+        assert_close(
+            merged.unbiased_variance().unwrap(),
+            whole.unbiased_variance().unwrap(),
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::common::*;
+
+/// A collection that computes the exponentially weighted moving average.
+///
+/// The smoothing factor is the rational number `alpha = NUM / DEN` within
+/// `(0, 1]`. The average starts from zero, and the bias towards zero in the
+/// first samples is corrected by dividing by `1 - (1 - alpha)^n`. That is, the
+/// `i`-th latest value is weighted by `(1 - alpha)^i` in the corrected average.
+///
+/// The average is updated by each pushed value, and it can be read at any time.
+/// Merging is exact, where the merged collection holds the later values.
+///
+/// ```rust
+/// use collected::Ewma;
+///
+/// let mut ewma: Ewma<f64, 1, 2> = vec![1.0, 2.0].into_iter().collect();
+/// assert_eq!(ewma.get(), Some(5.0 / 3.0));
+///
+/// ewma.extend(vec![3.0]);
+/// assert_eq!(ewma.get(), Some(17.0 / 7.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ewma<A, const NUM: u64, const DEN: u64> {
+    raw: f64,
+    decay: f64,
+    count: u64,
+    _phantom: PhantomData<A>,
+}
+
+impl<A, const NUM: u64, const DEN: u64> Ewma<A, NUM, DEN> {
+    /// The smoothing factor `NUM / DEN`.
+    pub const ALPHA: f64 = {
+        assert!(
+            NUM > 0 && NUM <= DEN,
+            "the smoothing factor must be within (0, 1]"
+        );
+        NUM as f64 / DEN as f64
+    };
+
+    /// Returns the bias-corrected average, or `None` if no values are collected.
+    pub fn get(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.raw / (1.0 - self.decay))
+    }
+
+    /// Returns the average without bias correction, which starts from zero.
+    pub fn raw(&self) -> f64 {
+        self.raw
+    }
+
+    /// Returns the number of collected values.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+impl<A, const NUM: u64, const DEN: u64> Default for Ewma<A, NUM, DEN> {
+    fn default() -> Self {
+        Self {
+            raw: 0.0,
+            decay: 1.0,
+            count: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, const NUM: u64, const DEN: u64> From<Ewma<A, NUM, DEN>> for Option<f64> {
+    fn from(collector: Ewma<A, NUM, DEN>) -> Self {
+        collector.get()
+    }
+}
+
+impl<A, const NUM: u64, const DEN: u64> FromIterator<A> for Ewma<A, NUM, DEN>
+where
+    A: ToF64,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut ewma = Self::default();
+        ewma.extend(iter);
+        ewma
+    }
+}
+
+impl<A, const NUM: u64, const DEN: u64> Extend<A> for Ewma<A, NUM, DEN>
+where
+    A: ToF64,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, const NUM: u64, const DEN: u64> Collector for Ewma<A, NUM, DEN>
+where
+    A: ToF64,
+{
+    type Item = A;
+    type Output = Option<f64>;
+
+    fn push(&mut self, item: A) {
+        let alpha = Self::ALPHA;
+        self.raw += alpha * (item.to_f64() - self.raw);
+        self.decay *= 1.0 - alpha;
+        self.count += 1;
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.raw = self.raw * other.decay + other.raw;
+        self.decay *= other.decay;
+        self.count += other.count;
+    }
+
+    fn finish(self) -> Option<f64> {
+        self.get()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const NUM: u64, const DEN: u64> FromParallelIterator<A> for Ewma<A, NUM, DEN>
+where
+    A: ToF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const NUM: u64, const DEN: u64> ParallelExtend<A> for Ewma<A, NUM, DEN>
+where
+    A: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn ewma_test() {
+        let mut ewma: Ewma<u32, 1, 2> = iter::once(1).collect();
+        assert_eq!(ewma.get(), Some(1.0));
+        assert_eq!(ewma.raw(), 0.5);
+
+        ewma.extend(vec![2, 3]);
+        assert_close(ewma.get().unwrap(), 4.25 / 1.75);
+        assert_close(ewma.raw(), 2.125);
+
+        let ewma: Ewma<f64, 1, 1> = vec![1.0, 5.0].into_iter().collect();
+        assert_eq!(ewma.finish(), Some(5.0));
+
+        let ewma: Ewma<f64, 1, 10> = iter::empty().collect();
+        assert_eq!(ewma.finish(), None);
+    }
+
+    #[test]
+    fn ewma_merge_test() {
+        let values: Vec<f64> = (0..50).map(|val| ((val * 7) % 11) as f64).collect();
+        let whole: Ewma<f64, 3, 10> = values.iter().copied().collect();
+
+        let mut merged: Ewma<f64, 3, 10> = values[..15].iter().copied().collect();
+        merged.merge(Ewma::default());
+        merged.merge(values[15..].iter().copied().collect());
+
+        assert_eq!(merged.count(), 50);
+        assert_close(merged.get().unwrap(), whole.get().unwrap());
+        assert_close(merged.raw(), whole.raw());
+    }
+}
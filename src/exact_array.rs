@@ -1,4 +1,6 @@
 use crate::common::*;
+#[cfg(feature = "serde")]
+use serde::{de::Error as _, Deserializer, Serializer};
 
 /// The collection type that collects values into an `[A; SIZE]` array from an iterator with exact size `SIZE`;
 #[derive(Debug)]
@@ -14,6 +16,11 @@ impl<A, const SIZE: usize> ExactArray<A, SIZE> {
         let ok = !self.overflow && self.len == SIZE;
         ok.then(|| unsafe { MaybeUninit::array_assume_init(self.array) })
     }
+
+    #[cfg(feature = "serde")]
+    fn as_slice(&self) -> &[A] {
+        unsafe { &*(&self.array[..self.len] as *const [MaybeUninit<A>] as *const [A]) }
+    }
 }
 
 impl<A, const SIZE: usize> FromIterator<A> for ExactArray<A, SIZE> {
@@ -65,6 +72,123 @@ impl<A, const SIZE: usize> Extend<A> for ExactArray<A, SIZE> {
     }
 }
 
+impl<A, const SIZE: usize> Collector for ExactArray<A, SIZE> {
+    type Item = A;
+    type Output = Option<[A; SIZE]>;
+
+    fn push(&mut self, item: A) {
+        if self.overflow {
+            return;
+        }
+
+        if self.len == SIZE {
+            self.overflow = true;
+        } else {
+            self.array[self.len].write(item);
+            self.len += 1;
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        let Self {
+            array,
+            len,
+            overflow,
+        } = other;
+        array[..len]
+            .iter()
+            .for_each(|item| self.push(unsafe { item.assume_init_read() }));
+        self.overflow |= overflow;
+    }
+
+    fn finish(self) -> Option<[A; SIZE]> {
+        self.into_array()
+    }
+
+    fn is_done(&self) -> bool {
+        self.overflow
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const SIZE: usize> FromParallelIterator<A> for ExactArray<A, SIZE>
+where
+    A: Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const SIZE: usize> ParallelExtend<A> for ExactArray<A, SIZE>
+where
+    A: Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize)]
+struct ExactArrayRef<'a, A> {
+    items: &'a [A],
+    overflow: bool,
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct ExactArrayOwned<A> {
+    items: Vec<A>,
+    overflow: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<A, const SIZE: usize> Serialize for ExactArray<A, SIZE>
+where
+    A: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ExactArrayRef {
+            items: self.as_slice(),
+            overflow: self.overflow,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A, const SIZE: usize> Deserialize<'de> for ExactArray<A, SIZE>
+where
+    A: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ExactArrayOwned { items, overflow } = ExactArrayOwned::deserialize(deserializer)?;
+        if items.len() > SIZE {
+            return Err(D::Error::invalid_length(
+                items.len(),
+                &alloc::format!("at most {} items", SIZE).as_str(),
+            ));
+        }
+        let mut array: Self = items.into_iter().collect();
+        array.overflow = overflow;
+        Ok(array)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -83,4 +207,19 @@ mod tests {
         let array: ExactArray<usize, 0> = vec![].into_iter().collect();
         assert_eq!(array.into_array(), Some([]));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn exact_array_serde_test() {
+        let array: ExactArray<usize, 3> = vec![3, 5].into_iter().collect();
+        let mut restored: ExactArray<usize, 3> =
+            bincode::deserialize(&bincode::serialize(&array).unwrap()).unwrap();
+        restored.extend(vec![1]);
+        assert_eq!(restored.into_array(), Some([3, 5, 1]));
+
+        let array: ExactArray<usize, 1> = vec![3, 5].into_iter().collect();
+        let restored: ExactArray<usize, 1> =
+            bincode::deserialize(&bincode::serialize(&array).unwrap()).unwrap();
+        assert_eq!(restored.into_array(), None);
+    }
 }
@@ -1,4 +1,6 @@
 use crate::common::*;
+#[cfg(feature = "serde")]
+use serde::{de::Error as _, Deserializer, Serializer};
 
 /// The collection type that collects values into an `[A; SIZE]` array from an iterator with minimum size `SIZE`;
 #[derive(Debug)]
@@ -13,6 +15,11 @@ impl<A, const SIZE: usize> FillArray<A, SIZE> {
         let ok = self.len == SIZE;
         ok.then(|| unsafe { MaybeUninit::array_assume_init(self.array) })
     }
+
+    #[cfg(feature = "serde")]
+    fn as_slice(&self) -> &[A] {
+        unsafe { &*(&self.array[..self.len] as *const [MaybeUninit<A>] as *const [A]) }
+    }
 }
 
 impl<A, const SIZE: usize> FromIterator<A> for FillArray<A, SIZE> {
@@ -20,10 +27,12 @@ impl<A, const SIZE: usize> FromIterator<A> for FillArray<A, SIZE> {
         let mut array = MaybeUninit::<A>::uninit_array::<SIZE>();
         let mut len = 0;
 
-        for item in iter.into_iter() {
-            if len == SIZE {
-                break;
-            }
+        let mut iter = iter.into_iter();
+        while len < SIZE {
+            let item = match iter.next() {
+                Some(item) => item,
+                None => break,
+            };
             array[len].write(item);
             len += 1;
         }
@@ -43,15 +52,102 @@ impl<A, const SIZE: usize> Default for FillArray<A, SIZE> {
 
 impl<A, const SIZE: usize> Extend<A> for FillArray<A, SIZE> {
     fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
-        for item in iter {
-            if self.len == SIZE {
-                break;
-            }
+        let mut iter = iter.into_iter();
+        while self.len < SIZE {
+            let item = match iter.next() {
+                Some(item) => item,
+                None => break,
+            };
+            self.array[self.len].write(item);
+            self.len += 1;
+        }
+    }
+}
+
+impl<A, const SIZE: usize> Collector for FillArray<A, SIZE> {
+    type Item = A;
+    type Output = Option<[A; SIZE]>;
 
+    fn push(&mut self, item: A) {
+        if self.len < SIZE {
             self.array[self.len].write(item);
             self.len += 1;
         }
     }
+
+    fn merge(&mut self, other: Self) {
+        let Self { array, len } = other;
+        array[..len]
+            .iter()
+            .for_each(|item| self.push(unsafe { item.assume_init_read() }));
+    }
+
+    fn finish(self) -> Option<[A; SIZE]> {
+        self.into_array()
+    }
+
+    fn is_done(&self) -> bool {
+        self.len == SIZE
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const SIZE: usize> FromParallelIterator<A> for FillArray<A, SIZE>
+where
+    A: Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const SIZE: usize> ParallelExtend<A> for FillArray<A, SIZE>
+where
+    A: Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<A, const SIZE: usize> Serialize for FillArray<A, SIZE>
+where
+    A: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.as_slice())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, A, const SIZE: usize> Deserialize<'de> for FillArray<A, SIZE>
+where
+    A: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let items = Vec::<A>::deserialize(deserializer)?;
+        if items.len() > SIZE {
+            return Err(D::Error::invalid_length(
+                items.len(),
+                &format!("at most {} items", SIZE).as_str(),
+            ));
+        }
+        Ok(items.into_iter().collect())
+    }
 }
 
 #[cfg(test)]
@@ -72,4 +168,17 @@ mod tests {
         let array: FillArray<usize, 0> = vec![].into_iter().collect();
         assert_eq!(array.into_array(), Some([]));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn fill_array_serde_test() {
+        let array: FillArray<usize, 3> = vec![3, 5].into_iter().collect();
+        let mut restored: FillArray<usize, 3> =
+            bincode::deserialize(&bincode::serialize(&array).unwrap()).unwrap();
+        restored.extend(vec![1, 4]);
+        assert_eq!(restored.into_array(), Some([3, 5, 1]));
+
+        let bytes = bincode::serialize(&vec![1usize, 2]).unwrap();
+        assert!(bincode::deserialize::<FillArray<usize, 1>>(&bytes).is_err());
+    }
 }
@@ -2,8 +2,9 @@ use crate::common::*;
 
 /// A collection that takes the first item when collected or extended from an iterator.
 ///
-/// By collecting/extending an iterator into `First`, each item is consumed and dropped.
+/// It stops pulling items from the iterator once the first item is taken.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct First<T> {
     inner: Option<T>,
 }
@@ -26,21 +27,65 @@ impl<T> Default for First<T> {
 
 impl<A> FromIterator<A> for First<A> {
     fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
-        let mut iter = iter.into_iter();
-        let first = iter.next();
-        iter.for_each(|_| ());
-
+        let first = iter.into_iter().next();
         Self { inner: first }
     }
 }
 
 impl<A> Extend<A> for First<A> {
     fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
-        let mut iter = iter.into_iter();
-        let first = iter.next();
-        iter.for_each(|_| ());
+        if self.inner.is_none() {
+            self.inner = iter.into_iter().next();
+        }
+    }
+}
+
+impl<A> Collector for First<A> {
+    type Item = A;
+    type Output = Option<A>;
+
+    fn push(&mut self, item: A) {
+        if self.inner.is_none() {
+            self.inner = Some(item);
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.inner = self.inner.take().or(other.inner);
+    }
 
-        self.inner = self.inner.take().or(first);
+    fn finish(self) -> Option<A> {
+        self.inner
+    }
+
+    fn is_done(&self) -> bool {
+        self.inner.is_some()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for First<A>
+where
+    A: Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for First<A>
+where
+    A: Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
     }
 }
 
@@ -49,6 +94,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::partialeq_to_none)]
     fn first_test() {
         {
             let mut first: First<_> = iter::empty().collect();
@@ -69,4 +115,15 @@ mod tests {
             assert!(first.get() == Some(&0));
         }
     }
+
+    #[test]
+    fn first_early_stop_test() {
+        let mut iter = 0..10;
+        let mut first: First<_> = iter.by_ref().collect();
+        assert_eq!(iter.next(), Some(1));
+
+        first.extend(iter.by_ref());
+        assert_eq!(iter.next(), Some(2));
+        assert!(first.get() == Some(&0));
+    }
 }
@@ -4,10 +4,12 @@ use crate::common::*;
 ///
 /// The wrapper maintains a [HashSet](HashSet) internally. If an incoming value `A` is duplicated,
 /// it clears the internal collections and ignores future values.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct FromUniqueHash<U, A>(Option<State<U, A>>)
 where
     A: Hash + Eq + Clone;
 
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 struct State<U, A>
 where
     A: Hash + Eq + Clone,
@@ -38,7 +40,7 @@ where
         let mut set = Some(HashSet::new());
         let inner: U = iter
             .into_iter()
-            .map(|item| {
+            .map_while(|item| {
                 let ok = set.as_mut().unwrap().insert(item.clone());
 
                 if ok {
@@ -48,8 +50,6 @@ where
                     None
                 }
             })
-            .fuse()
-            .flatten()
             .collect();
 
         let state = set.map(|set| State { inner, set });
@@ -68,14 +68,10 @@ where
             let State { inner, set } = state;
             let mut ok = true;
 
-            let iter = iter
-                .into_iter()
-                .map(|item| {
-                    ok &= set.insert(item.clone());
-                    ok.then(|| item)
-                })
-                .fuse()
-                .flatten();
+            let iter = iter.into_iter().map_while(|item| {
+                ok = set.insert(item.clone());
+                ok.then_some(item)
+            });
             inner.extend(iter);
 
             if !ok {
@@ -85,6 +81,69 @@ where
     }
 }
 
+impl<U, A> Collector for FromUniqueHash<U, A>
+where
+    U: Extend<A> + IntoIterator<Item = A>,
+    A: Hash + Eq + Clone,
+{
+    type Item = A;
+    type Output = Option<U>;
+
+    fn push(&mut self, item: A) {
+        if let Some(state) = &mut self.0 {
+            if state.set.insert(item.clone()) {
+                state.inner.extend(iter::once(item));
+            } else {
+                self.0 = None;
+            }
+        }
+    }
+
+    /// Fails the collection if both collections share any value.
+    fn merge(&mut self, other: Self) {
+        match other.0 {
+            Some(other) => other.inner.into_iter().for_each(|item| self.push(item)),
+            None => self.0 = None,
+        }
+    }
+
+    fn finish(self) -> Option<U> {
+        self.into_inner()
+    }
+
+    fn is_done(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<U, A> FromParallelIterator<A> for FromUniqueHash<U, A>
+where
+    U: FromIterator<A> + Extend<A> + IntoIterator<Item = A> + Send,
+    A: Hash + Eq + Clone + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<U, A> ParallelExtend<A> for FromUniqueHash<U, A>
+where
+    U: FromIterator<A> + Extend<A> + IntoIterator<Item = A> + Send,
+    A: Hash + Eq + Clone + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -103,4 +162,20 @@ mod tests {
         unique.extend(vec![4]);
         assert_eq!(unique.get(), None);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn unique_hash_serde_test() {
+        let unique: FromUniqueHash<Vec<usize>, _> = vec![1, 2, 3].into_iter().collect();
+        let mut restored: FromUniqueHash<Vec<usize>, _> =
+            bincode::deserialize(&bincode::serialize(&unique).unwrap()).unwrap();
+        restored.extend(vec![4]);
+        assert_eq!(restored.get(), Some(&vec![1, 2, 3, 4]));
+        restored.extend(vec![2]);
+        assert_eq!(restored.get(), None);
+
+        let failed: FromUniqueHash<Vec<usize>, usize> =
+            bincode::deserialize(&bincode::serialize(&restored).unwrap()).unwrap();
+        assert_eq!(failed.into_inner(), None);
+    }
 }
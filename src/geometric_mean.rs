@@ -0,0 +1,115 @@
+use crate::{common::*, LogProduct};
+
+/// A collection that computes the geometric mean of positive values.
+///
+/// It is built on [`LogProduct`], and takes the mean in log space. Once a zero,
+/// negative or NaN value arrives, the collection keeps a [`NonPositiveError`]
+/// with the position of the item.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GeometricMean<A>(LogProduct<A>);
+
+impl<A> GeometricMean<A> {
+    /// Returns the geometric mean, or `Ok(None)` if no values are collected.
+    pub fn get(&self) -> Result<Option<f64>, NonPositiveError> {
+        let ln = self.0.ln()?;
+        let count = self.0.count();
+        Ok((count > 0).then(|| math::exp(ln / count as f64)))
+    }
+
+    /// Returns the number of collected values.
+    pub fn count(&self) -> usize {
+        self.0.count()
+    }
+
+    pub fn into_inner(self) -> LogProduct<A> {
+        self.0
+    }
+}
+
+impl<A> FromIterator<A> for GeometricMean<A>
+where
+    A: ToF64,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<A> Extend<A> for GeometricMean<A>
+where
+    A: ToF64,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl<A> Collector for GeometricMean<A>
+where
+    A: ToF64,
+{
+    type Item = A;
+    type Output = Result<Option<f64>, NonPositiveError>;
+
+    fn push(&mut self, item: A) {
+        self.0.push(item);
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.0.merge(other.0);
+    }
+
+    fn finish(self) -> Self::Output {
+        self.get()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for GeometricMean<A>
+where
+    A: ToF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for GeometricMean<A>
+where
+    A: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn geometric_mean_test() {
+        let mut mean: GeometricMean<u32> = vec![1, 3].into_iter().collect();
+        mean.extend(vec![9]);
+        assert!((mean.get().unwrap().unwrap() - 3.0).abs() < 1e-12);
+
+        let mut lhs: GeometricMean<f64> = vec![1e-300; 500].into_iter().collect();
+        lhs.merge(vec![1e-300; 500].into_iter().collect());
+        assert_eq!(lhs.count(), 1000);
+        assert!((lhs.get().unwrap().unwrap() - 1e-300).abs() < 1e-310);
+
+        let mean: GeometricMean<f64> = iter::empty().collect();
+        assert_eq!(mean.finish(), Ok(None));
+
+        let mean: GeometricMean<i32> = vec![2, -2].into_iter().collect();
+        assert_eq!(mean.finish(), Err(NonPositiveError { index: 1 }));
+    }
+}
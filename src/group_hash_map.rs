@@ -2,6 +2,14 @@ use crate::common::*;
 
 /// A collection that groups the tuples `(key, value)` by key.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "K: Serialize + Hash + Eq, V: Serialize",
+        deserialize = "K: Deserialize<'de> + Hash + Eq, V: Deserialize<'de>"
+    ))
+)]
 pub struct GroupHashMap<K, V>(HashMap<K, Vec<V>>);
 
 impl<K, V> Default for GroupHashMap<K, V> {
@@ -15,8 +23,9 @@ where
     K: Hash + Eq,
 {
     fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
-        let map = iter.into_iter().into_group_map();
-        Self(map)
+        let mut map = Self::default();
+        map.extend(iter);
+        map
     }
 }
 
@@ -33,7 +42,7 @@ where
                     entry.get_mut().push(val);
                 }
                 hash_map::Entry::Vacant(entry) => {
-                    entry.insert(vec![val]);
+                    entry.insert(alloc::vec![val]);
                 }
             });
     }
@@ -49,10 +58,67 @@ impl<K, V> GroupHashMap<K, V> {
     }
 }
 
+impl<K, V> Collector for GroupHashMap<K, V>
+where
+    K: Hash + Eq,
+{
+    type Item = (K, V);
+    type Output = HashMap<K, Vec<V>>;
+
+    fn push(&mut self, (key, val): (K, V)) {
+        self.0.entry(key).or_default().push(val);
+    }
+
+    fn merge(&mut self, other: Self) {
+        other
+            .0
+            .into_iter()
+            .for_each(|(key, vals)| match self.0.entry(key) {
+                hash_map::Entry::Occupied(mut entry) => {
+                    entry.get_mut().extend(vals);
+                }
+                hash_map::Entry::Vacant(entry) => {
+                    entry.insert(vals);
+                }
+            });
+    }
+
+    fn finish(self) -> HashMap<K, Vec<V>> {
+        self.0
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<K, V> FromParallelIterator<(K, V)> for GroupHashMap<K, V>
+where
+    K: Hash + Eq + Send,
+    V: Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = (K, V)>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<K, V> ParallelExtend<(K, V)> for GroupHashMap<K, V>
+where
+    K: Hash + Eq + Send,
+    V: Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = (K, V)>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use maplit::hashmap;
 
     #[test]
     fn group_hash_map_test() {
@@ -60,20 +126,17 @@ mod tests {
             vec![('a', 1), ('a', 2), ('b', 3)].into_iter().collect();
         assert_eq!(
             map.get(),
-            &hashmap! {
-                'a' => vec![1, 2],
-                'b' => vec![3],
-            }
+            &vec![('a', vec![1, 2]), ('b', vec![3])]
+                .into_iter()
+                .collect::<HashMap<_, _>>()
         );
 
         map.extend(vec![('b', 4), ('c', 5)]);
         assert_eq!(
             map.get(),
-            &hashmap! {
-                'a' => vec![1, 2],
-                'b' => vec![3, 4],
-                'c' => vec![5],
-            }
+            &vec![('a', vec![1, 2]), ('b', vec![3, 4]), ('c', vec![5])]
+                .into_iter()
+                .collect::<HashMap<_, _>>()
         );
     }
 }
@@ -0,0 +1,152 @@
+use crate::{common::*, NeumaierSum};
+
+/// A collection that computes the harmonic mean of positive values.
+///
+/// It sums up the reciprocals of values by compensated summation. Once a zero,
+/// negative or NaN value arrives, the collection keeps a [`NonPositiveError`]
+/// with the position of the item.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HarmonicMean<A> {
+    reciprocal_sum: NeumaierSum<f64>,
+    error: Option<NonPositiveError>,
+    count: usize,
+    _phantom: PhantomData<A>,
+}
+
+impl<A> HarmonicMean<A> {
+    /// Returns the harmonic mean, or `Ok(None)` if no values are collected.
+    pub fn get(&self) -> Result<Option<f64>, NonPositiveError> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok((self.count > 0).then(|| self.count as f64 / self.reciprocal_sum.get())),
+        }
+    }
+
+    /// Returns the number of collected values.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<A> Default for HarmonicMean<A> {
+    fn default() -> Self {
+        Self {
+            reciprocal_sum: NeumaierSum::default(),
+            error: None,
+            count: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A> FromIterator<A> for HarmonicMean<A>
+where
+    A: ToF64,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut mean = Self::default();
+        mean.extend(iter);
+        mean
+    }
+}
+
+impl<A> Extend<A> for HarmonicMean<A>
+where
+    A: ToF64,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A> Collector for HarmonicMean<A>
+where
+    A: ToF64,
+{
+    type Item = A;
+    type Output = Result<Option<f64>, NonPositiveError>;
+
+    fn push(&mut self, item: A) {
+        let index = self.count;
+        self.count += 1;
+        if self.error.is_some() {
+            return;
+        }
+
+        let value = item.to_f64();
+        if value > 0.0 {
+            self.reciprocal_sum.push(value.recip());
+        } else {
+            self.error = Some(NonPositiveError { index });
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        if self.error.is_none() {
+            match other.error {
+                Some(NonPositiveError { index }) => {
+                    self.error = Some(NonPositiveError {
+                        index: self.count + index,
+                    })
+                }
+                None => self.reciprocal_sum.merge(other.reciprocal_sum),
+            }
+        }
+        self.count += other.count;
+    }
+
+    fn finish(self) -> Self::Output {
+        self.get()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for HarmonicMean<A>
+where
+    A: ToF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for HarmonicMean<A>
+where
+    A: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn harmonic_mean_test() {
+        let mut mean: HarmonicMean<u32> = vec![1, 4].into_iter().collect();
+        mean.extend(vec![4]);
+        assert_eq!(mean.get(), Ok(Some(2.0)));
+
+        let mut lhs: HarmonicMean<f64> = vec![2.0].into_iter().collect();
+        lhs.merge(vec![2.0, 0.5].into_iter().collect());
+        assert_eq!(lhs.finish(), Ok(Some(1.0)));
+
+        let mean: HarmonicMean<f64> = iter::empty().collect();
+        assert_eq!(mean.finish(), Ok(None));
+
+        let mut lhs: HarmonicMean<f64> = vec![1.0, 2.0].into_iter().collect();
+        lhs.merge(vec![3.0, 0.0, -1.0].into_iter().collect());
+        assert_eq!(lhs.count(), 5);
+        assert_eq!(lhs.finish(), Err(NonPositiveError { index: 3 }));
+    }
+}
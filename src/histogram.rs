@@ -0,0 +1,470 @@
+use crate::common::*;
+use core::{convert::TryFrom, fmt};
+
+/// The bins of a [`Histogram`].
+///
+/// Each bin covers a half-open range `[lower, upper)`, except that the last bin
+/// also includes its upper edge. Bins are created by [`linear()`](Bins::linear),
+/// [`exponential()`](Bins::exponential) or [`edges()`](Bins::edges).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "BinsKind", into = "BinsKind")
+)]
+pub struct Bins(BinsKind);
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum BinsKind {
+    /// `count` bins of equal width within `[min, max]`.
+    Linear { min: f64, max: f64, count: usize },
+    /// Bins between consecutive edges in ascending order.
+    Edges(Vec<f64>),
+}
+
+impl Bins {
+    /// Creates `count` bins of equal width within `[min, max]`.
+    ///
+    /// # Panics
+    /// Panics if `min` or `max` is not finite, `min >= max`, or `count` is zero.
+    pub fn linear(min: f64, max: f64, count: usize) -> Self {
+        Self::validate(BinsKind::Linear { min, max, count }).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates `count` bins, where the edges start at `start` and grow by `factor`.
+    ///
+    /// # Panics
+    /// Panics if `start` is not positive, `factor <= 1`, or `count` is zero.
+    pub fn exponential(start: f64, factor: f64, count: usize) -> Self {
+        assert!(start > 0.0, "start must be positive");
+        assert!(factor > 1.0, "factor must be greater than 1");
+        assert!(count > 0, "count must be positive");
+
+        let edges = iter::successors(Some(start), |edge| Some(edge * factor))
+            .take(count + 1)
+            .collect();
+        Self::edges(edges)
+    }
+
+    /// Creates bins from the given edges.
+    ///
+    /// # Panics
+    /// Panics if there are less than two edges, or the edges are not strictly ascending.
+    pub fn edges(edges: Vec<f64>) -> Self {
+        Self::validate(BinsKind::Edges(edges)).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Checks the invariants of the bins, which are shared by the constructors
+    /// and deserialization.
+    fn validate(kind: BinsKind) -> Result<Self, &'static str> {
+        match &kind {
+            &BinsKind::Linear { min, max, count } => {
+                if !(min.is_finite() && max.is_finite()) {
+                    return Err("min and max must be finite");
+                }
+                if min >= max {
+                    return Err("min must be less than max");
+                }
+                if count == 0 {
+                    return Err("count must be positive");
+                }
+            }
+            BinsKind::Edges(edges) => {
+                if edges.len() < 2 {
+                    return Err("expect at least two edges");
+                }
+                if !edges.windows(2).all(|pair| pair[0] < pair[1]) {
+                    return Err("the edges must be strictly ascending");
+                }
+            }
+        }
+        Ok(Self(kind))
+    }
+
+    /// Returns the number of bins.
+    pub fn len(&self) -> usize {
+        match &self.0 {
+            BinsKind::Linear { count, .. } => *count,
+            BinsKind::Edges(edges) => edges.len() - 1,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the lower and upper edges of the `index`-th bin.
+    pub fn range(&self, index: usize) -> (f64, f64) {
+        match &self.0 {
+            &BinsKind::Linear { min, max, count } => {
+                let upper = if index + 1 == count {
+                    max
+                } else {
+                    Self::linear_edge(min, max, count, index + 1)
+                };
+                (Self::linear_edge(min, max, count, index), upper)
+            }
+            BinsKind::Edges(edges) => (edges[index], edges[index + 1]),
+        }
+    }
+
+    /// Returns the `index`-th edge of linear bins, which is shared by
+    /// [`range()`](Bins::range) and [`locate()`](Bins::locate).
+    fn linear_edge(min: f64, max: f64, count: usize, index: usize) -> f64 {
+        min + (max - min) / count as f64 * index as f64
+    }
+
+    /// Returns the index of the bin containing `value`, or `Err(Ordering::Less)` for
+    /// underflow and `Err(Ordering::Greater)` for overflow.
+    fn locate(&self, value: f64) -> Result<usize, Ordering> {
+        match &self.0 {
+            &BinsKind::Linear { min, max, count } => {
+                if value < min {
+                    Err(Ordering::Less)
+                } else if value > max {
+                    Err(Ordering::Greater)
+                } else {
+                    // The estimate may be off by one due to rounding, so it is
+                    // corrected against the edges reported by range().
+                    let index = ((value - min) / (max - min) * count as f64) as usize;
+                    let index = index.min(count - 1);
+                    if index > 0 && value < Self::linear_edge(min, max, count, index) {
+                        Ok(index - 1)
+                    } else if index + 1 < count
+                        && value >= Self::linear_edge(min, max, count, index + 1)
+                    {
+                        Ok(index + 1)
+                    } else {
+                        Ok(index)
+                    }
+                }
+            }
+            BinsKind::Edges(edges) => {
+                let last = edges.len() - 1;
+                match edges.partition_point(|edge| *edge <= value) {
+                    0 => Err(Ordering::Less),
+                    index if index <= last => Ok(index - 1),
+                    _ if value == edges[last] => Ok(last - 1),
+                    _ => Err(Ordering::Greater),
+                }
+            }
+        }
+    }
+}
+
+impl TryFrom<BinsKind> for Bins {
+    type Error = &'static str;
+
+    fn try_from(kind: BinsKind) -> Result<Self, Self::Error> {
+        Self::validate(kind)
+    }
+}
+
+impl From<Bins> for BinsKind {
+    fn from(bins: Bins) -> Self {
+        bins.0
+    }
+}
+
+/// A collection that counts values in bins.
+///
+/// Unlike other collections, it is created from its [`Bins`] by
+/// [`new()`](Histogram::new) and then extended. Since it implements neither
+/// [`Default`] nor [`FromIterator`], it cannot be built by
+/// [`collect()`](Iterator::collect) or [`collected()`](crate::CollectedExt::collected),
+/// by the `collected()` of streams, or as a field of `#[derive(Collect)]`. With the
+/// `rayon` feature it implements `ParallelExtend` but not `FromParallelIterator`.
+/// Values out of the bins are counted as underflow or overflow, and NaN values
+/// are counted separately.
+///
+/// Histograms with the same bins can be merged. [`merge()`](Collector::merge)
+/// panics if the bins differ, while [`try_merge()`](Histogram::try_merge)
+/// returns an error.
+///
+/// The [`Display`](fmt::Display) implementation renders the histogram as ASCII
+/// bars, which are 40 characters long at most unless a width is specified.
+///
+/// ```rust
+/// use collected::{Bins, Histogram};
+///
+/// let mut histogram = Histogram::new(Bins::linear(0.0, 10.0, 5));
+/// histogram.extend(vec![1, 3, 3, 5, 12, -1]);
+/// assert_eq!(histogram.counts(), &[1, 2, 1, 0, 0]);
+/// assert_eq!((histogram.underflow(), histogram.overflow()), (1, 1));
+///
+/// println!("{}", histogram);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Histogram<A> {
+    bins: Bins,
+    counts: Vec<u64>,
+    underflow: u64,
+    overflow: u64,
+    nan_count: u64,
+    _phantom: PhantomData<fn(A)>,
+}
+
+impl<A> Histogram<A> {
+    /// Creates an empty histogram with the given bins.
+    pub fn new(bins: Bins) -> Self {
+        Self {
+            counts: alloc::vec![0; bins.len()],
+            bins,
+            underflow: 0,
+            overflow: 0,
+            nan_count: 0,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn bins(&self) -> &Bins {
+        &self.bins
+    }
+
+    /// Returns the counts of values in each bin.
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// Returns the number of values below the bins.
+    pub fn underflow(&self) -> u64 {
+        self.underflow
+    }
+
+    /// Returns the number of values above the bins.
+    pub fn overflow(&self) -> u64 {
+        self.overflow
+    }
+
+    /// Returns the number of NaN values.
+    pub fn nan_count(&self) -> u64 {
+        self.nan_count
+    }
+
+    /// Returns the number of collected values, including those out of the bins.
+    pub fn total(&self) -> u64 {
+        self.counts.iter().sum::<u64>() + self.underflow + self.overflow + self.nan_count
+    }
+
+    /// Iterates over the ranges and the counts of bins.
+    pub fn iter(&self) -> impl Iterator<Item = ((f64, f64), u64)> + '_ {
+        self.counts
+            .iter()
+            .enumerate()
+            .map(move |(index, &count)| (self.bins.range(index), count))
+    }
+
+    /// Merges another histogram with the same bins.
+    pub fn try_merge(&mut self, other: Self) -> Result<(), BinsMismatchError> {
+        if self.bins != other.bins {
+            return Err(BinsMismatchError);
+        }
+
+        self.counts
+            .iter_mut()
+            .zip(other.counts)
+            .for_each(|(lhs, rhs)| *lhs += rhs);
+        self.underflow += other.underflow;
+        self.overflow += other.overflow;
+        self.nan_count += other.nan_count;
+        Ok(())
+    }
+}
+
+impl<A> Extend<A> for Histogram<A>
+where
+    A: ToF64,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A> Collector for Histogram<A>
+where
+    A: ToF64,
+{
+    type Item = A;
+    type Output = Self;
+
+    fn push(&mut self, item: A) {
+        let value = item.to_f64();
+        if value.is_nan() {
+            self.nan_count += 1;
+            return;
+        }
+
+        match self.bins.locate(value) {
+            Ok(index) => self.counts[index] += 1,
+            Err(Ordering::Less) => self.underflow += 1,
+            Err(_) => self.overflow += 1,
+        }
+    }
+
+    /// Merges another histogram with the same bins.
+    ///
+    /// # Panics
+    /// Panics if the bins differ.
+    fn merge(&mut self, other: Self) {
+        self.try_merge(other).unwrap();
+    }
+
+    fn finish(self) -> Self {
+        self
+    }
+}
+
+impl<A> fmt::Display for Histogram<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = f.width().unwrap_or(40);
+        let max_count = self
+            .counts
+            .iter()
+            .copied()
+            .chain([self.underflow, self.overflow])
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let bar = |f: &mut fmt::Formatter<'_>, label: &dyn fmt::Display, count: u64| {
+            let len = (count as f64 / max_count as f64 * width as f64) as usize;
+            writeln!(f, "{:>24} {:>8} {:#<len$}", label, count, "", len = len)
+        };
+
+        if self.underflow > 0 {
+            bar(f, &"underflow", self.underflow)?;
+        }
+        let last = self.counts.len() - 1;
+        for (index, ((lower, upper), count)) in self.iter().enumerate() {
+            let close = if index == last { ']' } else { ')' };
+            bar(
+                f,
+                &alloc::format!("[{:.4}, {:.4}{}", lower, upper, close),
+                count,
+            )?;
+        }
+        if self.overflow > 0 {
+            bar(f, &"overflow", self.overflow)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for Histogram<A>
+where
+    A: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        use rayon::iter::ParallelIterator;
+
+        let empty = Self::new(self.bins.clone());
+        let other = par_iter
+            .into_par_iter()
+            .fold(
+                || empty.clone(),
+                |mut histogram, item| {
+                    histogram.push(item);
+                    histogram
+                },
+            )
+            .reduce(
+                || empty.clone(),
+                |mut lhs, rhs| {
+                    lhs.merge(rhs);
+                    lhs
+                },
+            );
+        self.merge(other);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_test() {
+        let mut histogram = Histogram::new(Bins::linear(0.0, 1.0, 4));
+        histogram.extend(vec![0.0, 0.1, 0.25, 0.6, 0.99, 1.0, 1.5, -0.1, f64::NAN]);
+        assert_eq!(histogram.counts(), &[2, 1, 1, 2]);
+        assert_eq!(
+            (
+                histogram.underflow(),
+                histogram.overflow(),
+                histogram.nan_count()
+            ),
+            (1, 1, 1)
+        );
+        assert_eq!(histogram.total(), 9);
+        assert_eq!(histogram.bins().range(1), (0.25, 0.5));
+
+        let mut histogram = Histogram::new(Bins::linear(0.0, 0.9, 3));
+        histogram.extend(vec![0.3, 0.6]);
+        assert_eq!(histogram.counts(), &[0, 1, 1]);
+        assert_eq!(histogram.bins().range(1), (0.3, 0.6));
+
+        let mut histogram = Histogram::new(Bins::exponential(1.0, 10.0, 3));
+        histogram.extend(vec![0u32, 1, 9, 10, 500, 1000, 1001]);
+        assert_eq!(histogram.counts(), &[2, 1, 2]);
+        assert_eq!((histogram.underflow(), histogram.overflow()), (1, 1));
+
+        let mut histogram = Histogram::new(Bins::edges(vec![-1.0, 0.0, 2.0]));
+        histogram.extend(vec![-1, 0, 1, 2, 3]);
+        assert_eq!(histogram.counts(), &[1, 3]);
+        assert_eq!(histogram.overflow(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn histogram_serde_test() {
+        let mut histogram = Histogram::new(Bins::edges(vec![0.0, 1.0, 2.0]));
+        histogram.extend(vec![0.5, 1.5]);
+        let restored: Histogram<f64> =
+            bincode::deserialize(&bincode::serialize(&histogram).unwrap()).unwrap();
+        assert_eq!(restored, histogram);
+
+        for kind in [
+            BinsKind::Edges(vec![]),
+            BinsKind::Edges(vec![0.0, 2.0, 1.0]),
+            BinsKind::Edges(vec![0.0, f64::NAN, 1.0]),
+            BinsKind::Linear {
+                min: 0.0,
+                max: 1.0,
+                count: 0,
+            },
+        ] {
+            let bytes = bincode::serialize(&kind).unwrap();
+            assert!(bincode::deserialize::<Bins>(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn histogram_merge_test() {
+        let mut lhs = Histogram::new(Bins::linear(0.0, 3.0, 3));
+        lhs.extend(vec![0, 1, 5]);
+        let mut rhs = Histogram::new(Bins::linear(0.0, 3.0, 3));
+        rhs.extend(vec![1, 2, -1]);
+        lhs.merge(rhs);
+        assert_eq!(lhs.counts(), &[1, 2, 1]);
+        assert_eq!((lhs.underflow(), lhs.overflow()), (1, 1));
+
+        let other = Histogram::new(Bins::linear(0.0, 3.0, 4));
+        assert_eq!(lhs.try_merge(other), Err(BinsMismatchError));
+
+        assert_eq!(
+            format!("{:8}", lhs),
+            concat!(
+                "               underflow        1 ####\n",
+                "        [0.0000, 1.0000)        1 ####\n",
+                "        [1.0000, 2.0000)        2 ########\n",
+                "        [2.0000, 3.0000]        1 ####\n",
+                "                overflow        1 ####\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::common::*;
+
+/// A collection that sums up `f32` or `f64` values by Kahan's compensated summation.
+///
+/// The rounding error of each addition is kept in a compensation term, which is
+/// carried across [`extend()`](Extend::extend) calls and merges.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KahanSum<A> {
+    sum: A,
+    compensation: A,
+}
+
+impl<A> KahanSum<A> {
+    /// Returns the running compensation term.
+    pub fn compensation(&self) -> &A {
+        &self.compensation
+    }
+}
+
+macro_rules! impl_kahan_sum {
+    ($ty:ty) => {
+        impl KahanSum<$ty> {
+            /// Returns the compensated sum.
+            pub fn get(&self) -> $ty {
+                self.sum + self.compensation
+            }
+        }
+
+        impl From<KahanSum<$ty>> for $ty {
+            fn from(collector: KahanSum<$ty>) -> Self {
+                collector.get()
+            }
+        }
+
+        impl FromIterator<$ty> for KahanSum<$ty> {
+            fn from_iter<T: IntoIterator<Item = $ty>>(iter: T) -> Self {
+                let mut sum = Self::default();
+                sum.extend(iter);
+                sum
+            }
+        }
+
+        impl Extend<$ty> for KahanSum<$ty> {
+            fn extend<T: IntoIterator<Item = $ty>>(&mut self, iter: T) {
+                iter.into_iter().for_each(|item| self.push(item));
+            }
+        }
+
+        impl Collector for KahanSum<$ty> {
+            type Item = $ty;
+            type Output = $ty;
+
+            fn push(&mut self, item: $ty) {
+                let value = item + self.compensation;
+                let sum = self.sum + value;
+                self.compensation = value - (sum - self.sum);
+                self.sum = sum;
+            }
+
+            fn merge(&mut self, other: Self) {
+                self.push(other.sum);
+                self.push(other.compensation);
+            }
+
+            fn finish(self) -> $ty {
+                self.get()
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl FromParallelIterator<$ty> for KahanSum<$ty> {
+            fn from_par_iter<I>(par_iter: I) -> Self
+            where
+                I: IntoParallelIterator<Item = $ty>,
+            {
+                par::collect(par_iter)
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl ParallelExtend<$ty> for KahanSum<$ty> {
+            fn par_extend<I>(&mut self, par_iter: I)
+            where
+                I: IntoParallelIterator<Item = $ty>,
+            {
+                par::extend(self, par_iter);
+            }
+        }
+    };
+}
+
+impl_kahan_sum!(f32);
+impl_kahan_sum!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kahan_sum_test() {
+        let values = || iter::once(1.0).chain(iter::repeat(1e-16).take(10));
+        assert_eq!(values().sum::<f64>(), 1.0);
+
+        let sum: KahanSum<f64> = values().collect();
+        assert_eq!(sum.get(), 1.000000000000001);
+
+        let mut sum: KahanSum<f64> = values().take(6).collect();
+        sum.extend(values().skip(6));
+        assert_eq!(sum.get(), 1.000000000000001);
+
+        let mut lhs: KahanSum<f64> = values().take(3).collect();
+        lhs.merge(values().skip(3).collect());
+        assert_eq!(lhs.finish(), 1.000000000000001);
+
+        let sum: KahanSum<f32> = iter::once(1.0)
+            .chain(iter::repeat(1e-8).take(100))
+            .collect();
+        assert_eq!(sum.get(), 1.000001);
+    }
+}
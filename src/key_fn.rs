@@ -0,0 +1,54 @@
+use core::cmp::Ordering;
+
+/// A key function given as a type, used by collections such as [`MaxByKey`](crate::MaxByKey).
+///
+/// Since the function is named by a type rather than stored as a closure, the
+/// collections keep implementing [`Default`] and work with [`collect()`](Iterator::collect).
+///
+/// ```rust
+/// use collected::{KeyFn, MaxByKey};
+///
+/// #[derive(Debug, Clone, Copy)]
+/// struct ByLen;
+///
+/// impl KeyFn<&str> for ByLen {
+///     type Key = usize;
+///
+///     fn key(item: &&str) -> usize {
+///         item.len()
+///     }
+/// }
+///
+/// let longest: MaxByKey<_, ByLen> = vec!["ab", "abcd", "abc"].into_iter().collect();
+/// assert_eq!(longest.unwrap(), "abcd");
+/// ```
+pub trait KeyFn<A> {
+    /// The key type by which items are ordered.
+    type Key: Ord;
+
+    /// Extracts the key from an item.
+    fn key(item: &A) -> Self::Key;
+}
+
+/// A comparator given as a type, used by collections such as [`MaxBy`](crate::MaxBy).
+///
+/// ```rust
+/// use collected::{CompareFn, MinBy};
+/// use std::cmp::Ordering;
+///
+/// #[derive(Debug, Clone, Copy)]
+/// struct ByFloat;
+///
+/// impl CompareFn<f64> for ByFloat {
+///     fn compare(lhs: &f64, rhs: &f64) -> Ordering {
+///         lhs.total_cmp(rhs)
+///     }
+/// }
+///
+/// let min: MinBy<_, ByFloat> = vec![2.5, -1.0, 3.0].into_iter().collect();
+/// assert_eq!(min.unwrap(), -1.0);
+/// ```
+pub trait CompareFn<A> {
+    /// Compares two items.
+    fn compare(lhs: &A, rhs: &A) -> Ordering;
+}
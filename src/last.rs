@@ -2,6 +2,7 @@ use crate::common::*;
 
 /// A collection that takes the last item when collected or extended from an iterator.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Last<T> {
     inner: Option<T>,
 }
@@ -36,11 +37,55 @@ impl<A> Extend<A> for Last<A> {
     }
 }
 
+impl<A> Collector for Last<A> {
+    type Item = A;
+    type Output = Option<A>;
+
+    fn push(&mut self, item: A) {
+        self.inner = Some(item);
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.inner = other.inner.or(self.inner.take());
+    }
+
+    fn finish(self) -> Option<A> {
+        self.inner
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for Last<A>
+where
+    A: Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for Last<A>
+where
+    A: Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::partialeq_to_none)]
     fn last_test() {
         {
             let mut last: Last<_> = iter::empty().collect();
@@ -2,6 +2,7 @@ use crate::common::*;
 
 /// A wrapper around collection `U` that retains only last `N` values.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LastN<U, A, const N: usize>
 where
     U: FromIterator<A>,
@@ -82,6 +83,57 @@ where
     }
 }
 
+impl<U, A, const N: usize> Collector for LastN<U, A, N>
+where
+    U: FromIterator<A>,
+{
+    type Item = A;
+    type Output = U;
+
+    fn push(&mut self, item: A) {
+        self.buffer.push_back(item);
+        if self.buffer.len() > N {
+            self.buffer.pop_front();
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        other.buffer.into_iter().for_each(|item| self.push(item));
+    }
+
+    fn finish(self) -> U {
+        self.into_inner()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<U, A, const N: usize> FromParallelIterator<A> for LastN<U, A, N>
+where
+    U: FromIterator<A> + Send,
+    A: Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<U, A, const N: usize> ParallelExtend<A> for LastN<U, A, N>
+where
+    U: FromIterator<A> + Send,
+    A: Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,4 +149,16 @@ mod tests {
         let last_n: LastN<Vec<_>, _, 0> = vec![2, 4, 1].into_iter().collect();
         assert_eq!(last_n.into_inner(), vec![]);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn last_n_serde_test() {
+        let mut last_n: LastN<Vec<_>, _, 3> = vec![3, 1, 5, 2].into_iter().collect();
+        let mut restored: LastN<Vec<_>, _, 3> =
+            bincode::deserialize(&bincode::serialize(&last_n).unwrap()).unwrap();
+
+        last_n.extend(vec![4, 6]);
+        restored.extend(vec![4, 6]);
+        assert_eq!(restored.into_inner(), last_n.into_inner());
+    }
 }
@@ -1,3 +1,4 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(
     feature = "unstable",
     feature(
@@ -12,6 +13,7 @@
 //! Every collection type in the crate implements [`FromIterator`](core::iter::FromIterator),
 //! [`Extend`](core::iter::Extend) and [`Default`](Default) traits. They can be built from
 //! [`collect()`](Iterator::collect), and can be updated by [`extend()`](Extend::extend).
+//! The exception is [`Histogram`], which is created from its bins and then extended.
 //!
 //! For example, it makes it easy to compute maximum and minimum value from an iterator
 //! using [`unzip()`](Iterator::unzip) in single step.
@@ -25,56 +27,189 @@
 //! assert_eq!(min.unwrap(), 1);
 //! assert_eq!(max.unwrap(), 6);
 //! ```
+//!
+//! The [`Tee`] collection generalizes it to more than two collections. It clones
+//! each item into every member collection in one pass.
+//!
+//! ```rust
+//! use collected::{Count, MaxVal, MinVal, Tee};
+//! let Tee((min, max, count)): Tee<(MinVal<_>, MaxVal<_>, Count<_>)> =
+//!     vec![3, 1, 5, 2, 4, 3, 6].into_iter().collect();
+//! assert_eq!(min.unwrap(), 1);
+//! assert_eq!(max.unwrap(), 6);
+//! assert_eq!(count.get(), 7);
+//! ```
+//!
+//! Every collection type also implements the [`Collector`] trait, which allows
+//! generic code to push items, merge partial results and take the final output
+//! through a single interface. The exceptions are [`CheckedSum`] and
+//! [`CheckedProduct`], whose overflow positions cannot be recovered by merging.
+//!
+//! # `no_std` support
+//!
+//! The crate builds under `no_std` with the `alloc` crate when the default `std`
+//! feature is disabled. The collections backed by hash tables, namely [`GroupHashMap`],
+//! [`UniqueHashSet`], [`FromUniqueHash`] and [`UniquifyHash`], require either the `std`
+//! or the `hashbrown` feature. [`LogProduct`] and [`GeometricMean`] require either the
+//! `std` or the `libm` feature.
+
+extern crate alloc;
 
 mod add;
+mod all_max;
+mod all_max_by_key;
+mod all_min;
+mod all_min_by_key;
+mod arg_max;
+mod arg_min;
+mod bivariate;
+mod checked_product;
+mod checked_sum;
+mod collector;
 mod common;
 mod count;
+mod error;
+mod ewm_variance;
+mod ewma;
 #[cfg(feature = "unstable")]
 mod exact_array;
 #[cfg(feature = "unstable")]
 mod fill_array;
 mod first;
+#[cfg(any(feature = "std", feature = "hashbrown"))]
 mod from_unique_hash;
 mod from_unique_ord;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod geometric_mean;
+#[cfg(any(feature = "std", feature = "hashbrown"))]
 mod group_hash_map;
+mod harmonic_mean;
+mod histogram;
+mod kahan_sum;
+mod key_fn;
 mod last;
 mod last_n;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod log_product;
 mod max;
+mod max_by;
+mod max_by_key;
+mod max_f;
+mod mean;
 mod min;
+mod min_by;
+mod min_by_key;
+mod min_f;
+mod min_max;
+mod moments4;
 mod mul;
+mod nan_policy;
+mod neumaier_sum;
 mod noop;
+mod num;
+#[cfg(feature = "rayon")]
+mod par;
 mod product;
+mod quantiles;
+mod saturating_sum;
+#[cfg(feature = "futures")]
+mod stream;
 mod sum;
+mod t_digest;
+mod tee;
+#[cfg(test)]
+mod test_util;
+mod tie_break;
 mod topk;
+mod topk_by_key;
+mod topk_f;
 mod unique_btree_set;
+#[cfg(any(feature = "std", feature = "hashbrown"))]
 mod unique_hash_set;
-#[cfg(feature = "indexmap")]
+#[cfg(all(feature = "std", feature = "indexmap"))]
 mod unique_index_set;
+#[cfg(any(feature = "std", feature = "hashbrown"))]
 mod uniquify_hash;
 mod uniquify_ord;
+mod variance;
+mod weighted_mean;
+mod weighted_variance;
+mod wrapping_sum;
 
 pub use add::*;
+pub use all_max::*;
+pub use all_max_by_key::*;
+pub use all_min::*;
+pub use all_min_by_key::*;
+pub use arg_max::*;
+pub use arg_min::*;
+pub use bivariate::*;
+pub use checked_product::*;
+pub use checked_sum::*;
+#[cfg(feature = "derive")]
+pub use collected_derive::Collect;
+pub use collector::*;
 pub use count::*;
+pub use error::*;
+pub use ewm_variance::*;
+pub use ewma::*;
 #[cfg(feature = "unstable")]
 pub use exact_array::*;
 #[cfg(feature = "unstable")]
 pub use fill_array::*;
 pub use first::*;
+#[cfg(any(feature = "std", feature = "hashbrown"))]
 pub use from_unique_hash::*;
 pub use from_unique_ord::*;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use geometric_mean::*;
+#[cfg(any(feature = "std", feature = "hashbrown"))]
 pub use group_hash_map::*;
+pub use harmonic_mean::*;
+pub use histogram::*;
+pub use kahan_sum::*;
+pub use key_fn::*;
 pub use last::*;
 pub use last_n::*;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use log_product::*;
 pub use max::*;
+pub use max_by::*;
+pub use max_by_key::*;
+pub use max_f::*;
+pub use mean::*;
 pub use min::*;
+pub use min_by::*;
+pub use min_by_key::*;
+pub use min_f::*;
+pub use min_max::*;
+pub use moments4::*;
 pub use mul::*;
+pub use nan_policy::*;
+pub use neumaier_sum::*;
 pub use noop::*;
+pub use num::*;
 pub use product::*;
+pub use quantiles::*;
+pub use saturating_sum::*;
+#[cfg(feature = "futures")]
+pub use stream::*;
 pub use sum::*;
+pub use t_digest::*;
+pub use tee::*;
+pub use tie_break::*;
 pub use topk::*;
+pub use topk_by_key::*;
+pub use topk_f::*;
 pub use unique_btree_set::*;
+#[cfg(any(feature = "std", feature = "hashbrown"))]
 pub use unique_hash_set::*;
-#[cfg(feature = "indexmap")]
+#[cfg(all(feature = "std", feature = "indexmap"))]
 pub use unique_index_set::*;
+#[cfg(any(feature = "std", feature = "hashbrown"))]
 pub use uniquify_hash::*;
 pub use uniquify_ord::*;
+pub use variance::*;
+pub use weighted_mean::*;
+pub use weighted_variance::*;
+pub use wrapping_sum::*;
@@ -0,0 +1,177 @@
+use crate::{common::*, NeumaierSum};
+
+/// A collection that multiplies positive values in log space.
+///
+/// It sums up the natural logarithms of values by compensated summation, so that
+/// the product of many probabilities or growth factors neither underflows nor
+/// overflows in log space. Once a zero, negative or NaN value arrives, the
+/// collection keeps a [`NonPositiveError`] with the position of the item.
+///
+/// ```rust
+/// use collected::LogProduct;
+///
+/// let product: LogProduct<f64> = vec![1e-200; 10].into_iter().collect();
+/// assert!((product.ln().unwrap() - 10.0 * 1e-200f64.ln()).abs() < 1e-9);
+/// assert_eq!(product.get(), Ok(0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LogProduct<A> {
+    log_sum: NeumaierSum<f64>,
+    error: Option<NonPositiveError>,
+    count: usize,
+    _phantom: PhantomData<A>,
+}
+
+impl<A> LogProduct<A> {
+    /// Returns the natural logarithm of the product.
+    pub fn ln(&self) -> Result<f64, NonPositiveError> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.log_sum.get()),
+        }
+    }
+
+    /// Returns the product, which may underflow to zero or overflow to infinity.
+    pub fn get(&self) -> Result<f64, NonPositiveError> {
+        self.ln().map(math::exp)
+    }
+
+    /// Returns the number of collected values.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<A> Default for LogProduct<A> {
+    fn default() -> Self {
+        Self {
+            log_sum: NeumaierSum::default(),
+            error: None,
+            count: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A> FromIterator<A> for LogProduct<A>
+where
+    A: ToF64,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut product = Self::default();
+        product.extend(iter);
+        product
+    }
+}
+
+impl<A> Extend<A> for LogProduct<A>
+where
+    A: ToF64,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A> Collector for LogProduct<A>
+where
+    A: ToF64,
+{
+    type Item = A;
+    type Output = Self;
+
+    fn push(&mut self, item: A) {
+        let index = self.count;
+        self.count += 1;
+        if self.error.is_some() {
+            return;
+        }
+
+        let value = item.to_f64();
+        if value > 0.0 {
+            self.log_sum.push(math::ln(value));
+        } else {
+            self.error = Some(NonPositiveError { index });
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        if self.error.is_none() {
+            match other.error {
+                Some(NonPositiveError { index }) => {
+                    self.error = Some(NonPositiveError {
+                        index: self.count + index,
+                    })
+                }
+                None => self.log_sum.merge(other.log_sum),
+            }
+        }
+        self.count += other.count;
+    }
+
+    fn finish(self) -> Self {
+        self
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for LogProduct<A>
+where
+    A: ToF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for LogProduct<A>
+where
+    A: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_product_test() {
+        let mut product: LogProduct<u32> = vec![2, 4].into_iter().collect();
+        product.extend(vec![8]);
+        assert!((product.ln().unwrap() - 64f64.ln()).abs() < 1e-12);
+        assert!((product.get().unwrap() - 64.0).abs() < 1e-9);
+
+        let product: LogProduct<f64> = vec![1e300, 1e300, 1e-300].into_iter().collect();
+        assert!((product.get().unwrap() - 1e300).abs() < 1e288);
+
+        let product: LogProduct<f64> = iter::empty().collect();
+        assert_eq!(product.get(), Ok(1.0));
+
+        let mut product: LogProduct<f64> = vec![0.5, 0.0, -1.0].into_iter().collect();
+        product.extend(vec![2.0]);
+        assert_eq!(product.ln(), Err(NonPositiveError { index: 1 }));
+        assert_eq!(product.count(), 4);
+    }
+
+    #[test]
+    fn log_product_merge_test() {
+        let mut lhs: LogProduct<f64> = vec![0.5, 4.0].into_iter().collect();
+        lhs.merge(vec![3.0].into_iter().collect());
+        assert!((lhs.get().unwrap() - 6.0).abs() < 1e-12);
+
+        lhs.merge(vec![1.0, f64::NAN].into_iter().collect());
+        lhs.merge(vec![-1.0].into_iter().collect());
+        assert_eq!(lhs.finish().get(), Err(NonPositiveError { index: 4 }));
+    }
+}
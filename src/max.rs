@@ -2,6 +2,7 @@ use crate::common::*;
 
 /// A collection that computes the maximum value.
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MaxVal<A>(Option<A>);
 
 impl<A> FromIterator<A> for MaxVal<A>
@@ -9,7 +10,7 @@ where
     A: Ord,
 {
     fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
-        let max = iter.into_iter().fold1(|lhs, rhs| lhs.max(rhs));
+        let max = iter.into_iter().reduce(|lhs, rhs| lhs.max(rhs));
         Self(max)
     }
 }
@@ -44,11 +45,63 @@ where
             .take()
             .into_iter()
             .chain(iter)
-            .fold1(|lhs, rhs| lhs.max(rhs));
+            .reduce(|lhs, rhs| lhs.max(rhs));
         self.0 = max;
     }
 }
 
+impl<A> Collector for MaxVal<A>
+where
+    A: Ord,
+{
+    type Item = A;
+    type Output = Option<A>;
+
+    fn push(&mut self, item: A) {
+        let max = match self.0.take() {
+            Some(lhs) => lhs.max(item),
+            None => item,
+        };
+        self.0 = Some(max);
+    }
+
+    fn merge(&mut self, other: Self) {
+        if let Some(rhs) = other.0 {
+            self.push(rhs);
+        }
+    }
+
+    fn finish(self) -> Option<A> {
+        self.0
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for MaxVal<A>
+where
+    A: Ord + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for MaxVal<A>
+where
+    A: Ord + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
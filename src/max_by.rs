@@ -0,0 +1,147 @@
+use crate::common::*;
+
+/// A collection that computes the maximum item according to the comparator `F`.
+///
+/// Among equal items, the last one is kept, as
+/// [`Iterator::max_by()`](Iterator::max_by) does.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MaxBy<A, F> {
+    inner: Option<A>,
+    _phantom: PhantomData<F>,
+}
+
+impl<A, F> MaxBy<A, F> {
+    pub fn unwrap(self) -> A {
+        self.inner.unwrap()
+    }
+
+    pub fn get(&self) -> Option<&A> {
+        self.inner.as_ref()
+    }
+
+    pub fn into_inner(self) -> Option<A> {
+        self.inner
+    }
+}
+
+impl<A, F> Default for MaxBy<A, F> {
+    fn default() -> Self {
+        Self {
+            inner: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, F> From<MaxBy<A, F>> for Option<A> {
+    fn from(collector: MaxBy<A, F>) -> Self {
+        collector.inner
+    }
+}
+
+impl<A, F> FromIterator<A> for MaxBy<A, F>
+where
+    F: CompareFn<A>,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut max = Self::default();
+        max.extend(iter);
+        max
+    }
+}
+
+impl<A, F> Extend<A> for MaxBy<A, F>
+where
+    F: CompareFn<A>,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        let max = self
+            .inner
+            .take()
+            .into_iter()
+            .chain(iter)
+            .reduce(|lhs, rhs| cmp::max_by(lhs, rhs, F::compare));
+        self.inner = max;
+    }
+}
+
+impl<A, F> Collector for MaxBy<A, F>
+where
+    F: CompareFn<A>,
+{
+    type Item = A;
+    type Output = Option<A>;
+
+    fn push(&mut self, item: A) {
+        let max = match self.inner.take() {
+            Some(lhs) => cmp::max_by(lhs, item, F::compare),
+            None => item,
+        };
+        self.inner = Some(max);
+    }
+
+    fn merge(&mut self, other: Self) {
+        if let Some(rhs) = other.inner {
+            self.push(rhs);
+        }
+    }
+
+    fn finish(self) -> Option<A> {
+        self.inner
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, F> FromParallelIterator<A> for MaxBy<A, F>
+where
+    A: Send,
+    F: CompareFn<A> + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, F> ParallelExtend<A> for MaxBy<A, F>
+where
+    A: Send,
+    F: CompareFn<A> + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy)]
+    struct ByFloat;
+
+    impl CompareFn<f64> for ByFloat {
+        fn compare(lhs: &f64, rhs: &f64) -> Ordering {
+            lhs.partial_cmp(rhs).unwrap()
+        }
+    }
+
+    #[test]
+    fn max_by_test() {
+        let mut max: MaxBy<_, ByFloat> = vec![1.5, -2.0, 3.0].into_iter().collect();
+        assert_eq!(max.get(), Some(&3.0));
+
+        max.extend(vec![f64::NEG_INFINITY, 3.0]);
+        assert_eq!(max.get(), Some(&3.0));
+
+        max.merge(vec![8.0].into_iter().collect());
+        assert_eq!(max.finish(), Some(8.0));
+    }
+}
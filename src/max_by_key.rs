@@ -0,0 +1,150 @@
+use crate::common::*;
+
+/// A collection that computes the item with the maximum key given by `F`.
+///
+/// Among items with equal keys, the last one is kept, as
+/// [`Iterator::max_by_key()`](Iterator::max_by_key) does.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MaxByKey<A, F> {
+    inner: Option<A>,
+    _phantom: PhantomData<F>,
+}
+
+impl<A, F> MaxByKey<A, F> {
+    pub fn unwrap(self) -> A {
+        self.inner.unwrap()
+    }
+
+    pub fn get(&self) -> Option<&A> {
+        self.inner.as_ref()
+    }
+
+    pub fn into_inner(self) -> Option<A> {
+        self.inner
+    }
+}
+
+impl<A, F> Default for MaxByKey<A, F> {
+    fn default() -> Self {
+        Self {
+            inner: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, F> From<MaxByKey<A, F>> for Option<A> {
+    fn from(collector: MaxByKey<A, F>) -> Self {
+        collector.inner
+    }
+}
+
+impl<A, F> FromIterator<A> for MaxByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut max = Self::default();
+        max.extend(iter);
+        max
+    }
+}
+
+impl<A, F> Extend<A> for MaxByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        let max = self
+            .inner
+            .take()
+            .into_iter()
+            .chain(iter)
+            .reduce(|lhs, rhs| cmp::max_by_key(lhs, rhs, F::key));
+        self.inner = max;
+    }
+}
+
+impl<A, F> Collector for MaxByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    type Item = A;
+    type Output = Option<A>;
+
+    fn push(&mut self, item: A) {
+        let max = match self.inner.take() {
+            Some(lhs) => cmp::max_by_key(lhs, item, F::key),
+            None => item,
+        };
+        self.inner = Some(max);
+    }
+
+    fn merge(&mut self, other: Self) {
+        if let Some(rhs) = other.inner {
+            self.push(rhs);
+        }
+    }
+
+    fn finish(self) -> Option<A> {
+        self.inner
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, F> FromParallelIterator<A> for MaxByKey<A, F>
+where
+    A: Send,
+    F: KeyFn<A> + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, F> ParallelExtend<A> for MaxByKey<A, F>
+where
+    A: Send,
+    F: KeyFn<A> + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy)]
+    struct ByFirst;
+
+    impl KeyFn<(usize, char)> for ByFirst {
+        type Key = usize;
+
+        fn key(item: &(usize, char)) -> usize {
+            item.0
+        }
+    }
+
+    #[test]
+    fn max_by_key_test() {
+        let mut max: MaxByKey<_, ByFirst> =
+            vec![(1, 'a'), (3, 'b'), (2, 'c')].into_iter().collect();
+        assert_eq!(max.get(), Some(&(3, 'b')));
+
+        max.extend(vec![(3, 'd'), (0, 'e')]);
+        assert_eq!(max.get(), Some(&(3, 'd')));
+
+        max.merge(vec![(3, 'f')].into_iter().collect());
+        assert_eq!(max.finish(), Some((3, 'f')));
+    }
+}
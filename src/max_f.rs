@@ -0,0 +1,195 @@
+use crate::common::*;
+
+/// A collection that computes the maximum `f32` or `f64` value.
+///
+/// NaN values are treated according to the policy `P`, which defaults to
+/// [`IgnoreNan`]. The number of NaN values seen is counted regardless of the policy.
+///
+/// ```rust
+/// use collected::{MaxF, NanGreatest};
+///
+/// let max: MaxF<f64> = vec![1.0, f64::NAN, 3.0].into_iter().collect();
+/// assert_eq!(max.get(), Some(3.0));
+/// assert_eq!(max.nan_count(), 1);
+///
+/// let max: MaxF<f64, NanGreatest> = vec![1.0, f64::NAN, 3.0].into_iter().collect();
+/// assert!(max.unwrap().is_nan());
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MaxF<A, P = IgnoreNan> {
+    max: Option<A>,
+    nan_count: usize,
+    _phantom: PhantomData<P>,
+}
+
+impl<A, P> MaxF<A, P> {
+    pub fn unwrap(self) -> A {
+        self.max.unwrap()
+    }
+
+    pub fn get(&self) -> Option<A>
+    where
+        A: Copy,
+    {
+        self.max
+    }
+
+    pub fn into_inner(self) -> Option<A> {
+        self.max
+    }
+
+    /// Returns the number of NaN values seen.
+    pub fn nan_count(&self) -> usize {
+        self.nan_count
+    }
+}
+
+impl<A, P> MaxF<A, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    fn push_value(&mut self, item: A) {
+        let max = match self.max {
+            None => item,
+            Some(max) if P::PROPAGATE_NAN && max.is_nan() => max,
+            Some(_) if P::PROPAGATE_NAN && item.is_nan() => item,
+            Some(max) => cmp::max_by(max, item, P::compare),
+        };
+        self.max = Some(max);
+    }
+}
+
+impl<A, P> Default for MaxF<A, P> {
+    fn default() -> Self {
+        Self {
+            max: None,
+            nan_count: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, P> From<MaxF<A, P>> for Option<A> {
+    fn from(collector: MaxF<A, P>) -> Self {
+        collector.max
+    }
+}
+
+impl<A, P> FromIterator<A> for MaxF<A, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut max = Self::default();
+        max.extend(iter);
+        max
+    }
+}
+
+impl<A, P> Extend<A> for MaxF<A, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, P> Collector for MaxF<A, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    type Item = A;
+    type Output = Option<A>;
+
+    fn push(&mut self, item: A) {
+        if item.is_nan() {
+            self.nan_count += 1;
+            if P::SKIP_NAN {
+                return;
+            }
+        }
+        self.push_value(item);
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.nan_count += other.nan_count;
+        if let Some(max) = other.max {
+            self.push_value(max);
+        }
+    }
+
+    fn finish(self) -> Option<A> {
+        self.max
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, P> FromParallelIterator<A> for MaxF<A, P>
+where
+    A: Float + Send,
+    P: NanPolicy + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, P> ParallelExtend<A> for MaxF<A, P>
+where
+    A: Float + Send,
+    P: NanPolicy + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NanGreatest, PropagateNan, TotalOrder};
+
+    #[test]
+    fn max_f_test() {
+        let values = || vec![1.0, f64::NAN, 3.0, -f64::NAN, 2.0];
+
+        let max: MaxF<f64> = values().into_iter().collect();
+        assert_eq!((max.get(), max.nan_count()), (Some(3.0), 2));
+
+        let max: MaxF<f64, PropagateNan> = values().into_iter().collect();
+        assert!(max.unwrap().is_nan());
+
+        let max: MaxF<f64, NanGreatest> = values().into_iter().collect();
+        assert!(max.unwrap().is_nan());
+
+        let max: MaxF<f64, TotalOrder> = vec![1.0, -f64::NAN, 3.0].into_iter().collect();
+        assert_eq!(max.get(), Some(3.0));
+
+        let max: MaxF<f32> = vec![f32::NAN].into_iter().collect();
+        assert_eq!((max.get(), max.nan_count()), (None, 1));
+    }
+
+    #[test]
+    fn max_f_merge_test() {
+        let mut lhs: MaxF<f64> = vec![1.0, f64::NAN].into_iter().collect();
+        lhs.merge(vec![f64::NAN, 0.5, 4.0].into_iter().collect());
+        assert_eq!((lhs.get(), lhs.nan_count()), (Some(4.0), 2));
+
+        let mut lhs: MaxF<f64, PropagateNan> = vec![1.0, f64::NAN].into_iter().collect();
+        lhs.merge(vec![4.0].into_iter().collect());
+        assert!(lhs.finish().unwrap().is_nan());
+    }
+}
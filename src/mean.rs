@@ -0,0 +1,157 @@
+use crate::common::*;
+
+/// A collection that computes the arithmetic mean of integer or float values.
+///
+/// It updates the running mean incrementally by `mean += (x - mean) / n`,
+/// which neither overflows on large integers nor loses precision as a large
+/// running sum does.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MeanVal<A> {
+    count: u64,
+    mean: f64,
+    _phantom: PhantomData<A>,
+}
+
+impl<A> MeanVal<A> {
+    /// Returns the mean, or `None` if no values are collected.
+    pub fn get(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    pub fn unwrap(self) -> f64 {
+        self.get().unwrap()
+    }
+
+    /// Returns the number of collected values.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+impl<A> Default for MeanVal<A> {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A> From<MeanVal<A>> for Option<f64> {
+    fn from(collector: MeanVal<A>) -> Self {
+        collector.get()
+    }
+}
+
+impl<A> FromIterator<A> for MeanVal<A>
+where
+    A: ToF64,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut mean = Self::default();
+        mean.extend(iter);
+        mean
+    }
+}
+
+impl<A> Extend<A> for MeanVal<A>
+where
+    A: ToF64,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A> Collector for MeanVal<A>
+where
+    A: ToF64,
+{
+    type Item = A;
+    type Output = Option<f64>;
+
+    fn push(&mut self, item: A) {
+        self.count += 1;
+        self.mean += (item.to_f64() - self.mean) / self.count as f64;
+    }
+
+    fn merge(&mut self, other: Self) {
+        if other.count == 0 {
+            return;
+        }
+
+        let count = self.count + other.count;
+        self.mean += (other.mean - self.mean) * (other.count as f64 / count as f64);
+        self.count = count;
+    }
+
+    fn finish(self) -> Option<f64> {
+        self.get()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for MeanVal<A>
+where
+    A: ToF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for MeanVal<A>
+where
+    A: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn mean_test() {
+        let mut mean: MeanVal<u64> = (1..=4).collect();
+        assert_eq!(mean.get(), Some(2.5));
+
+        mean.extend(5..=7);
+        assert_eq!(mean.get(), Some(4.0));
+        assert_eq!(mean.count(), 7);
+
+        let mean: MeanVal<u64> = iter::repeat(u64::MAX).take(4).collect();
+        assert_eq!(mean.unwrap(), u64::MAX as f64);
+
+        let mean: MeanVal<f32> = iter::empty().collect();
+        assert_eq!(mean.get(), None);
+    }
+
+    #[test]
+    fn mean_merge_test() {
+        let mut lhs: MeanVal<i32> = vec![1, 2, 3].into_iter().collect();
+        let rhs: MeanVal<i32> = vec![10, 20].into_iter().collect();
+        lhs.merge(rhs);
+        assert_eq!(lhs.count(), 5);
+        assert_close(lhs.unwrap(), 7.2);
+
+        lhs.merge(MeanVal::default());
+        assert_close(lhs.unwrap(), 7.2);
+
+        let mut empty = MeanVal::<i32>::default();
+        empty.merge(vec![4, 6].into_iter().collect());
+        assert_eq!(empty.get(), Some(5.0));
+    }
+}
@@ -0,0 +1,150 @@
+use crate::common::*;
+
+/// A collection that computes the item with the minimum key given by `F`.
+///
+/// Among items with equal keys, the first one is kept, as
+/// [`Iterator::min_by_key()`](Iterator::min_by_key) does.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinByKey<A, F> {
+    inner: Option<A>,
+    _phantom: PhantomData<F>,
+}
+
+impl<A, F> MinByKey<A, F> {
+    pub fn unwrap(self) -> A {
+        self.inner.unwrap()
+    }
+
+    pub fn get(&self) -> Option<&A> {
+        self.inner.as_ref()
+    }
+
+    pub fn into_inner(self) -> Option<A> {
+        self.inner
+    }
+}
+
+impl<A, F> Default for MinByKey<A, F> {
+    fn default() -> Self {
+        Self {
+            inner: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, F> From<MinByKey<A, F>> for Option<A> {
+    fn from(collector: MinByKey<A, F>) -> Self {
+        collector.inner
+    }
+}
+
+impl<A, F> FromIterator<A> for MinByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut min = Self::default();
+        min.extend(iter);
+        min
+    }
+}
+
+impl<A, F> Extend<A> for MinByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        let min = self
+            .inner
+            .take()
+            .into_iter()
+            .chain(iter)
+            .reduce(|lhs, rhs| cmp::min_by_key(lhs, rhs, F::key));
+        self.inner = min;
+    }
+}
+
+impl<A, F> Collector for MinByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    type Item = A;
+    type Output = Option<A>;
+
+    fn push(&mut self, item: A) {
+        let min = match self.inner.take() {
+            Some(lhs) => cmp::min_by_key(lhs, item, F::key),
+            None => item,
+        };
+        self.inner = Some(min);
+    }
+
+    fn merge(&mut self, other: Self) {
+        if let Some(rhs) = other.inner {
+            self.push(rhs);
+        }
+    }
+
+    fn finish(self) -> Option<A> {
+        self.inner
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, F> FromParallelIterator<A> for MinByKey<A, F>
+where
+    A: Send,
+    F: KeyFn<A> + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, F> ParallelExtend<A> for MinByKey<A, F>
+where
+    A: Send,
+    F: KeyFn<A> + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy)]
+    struct ByFirst;
+
+    impl KeyFn<(usize, char)> for ByFirst {
+        type Key = usize;
+
+        fn key(item: &(usize, char)) -> usize {
+            item.0
+        }
+    }
+
+    #[test]
+    fn min_by_key_test() {
+        let mut min: MinByKey<_, ByFirst> =
+            vec![(2, 'a'), (1, 'b'), (3, 'c')].into_iter().collect();
+        assert_eq!(min.get(), Some(&(1, 'b')));
+
+        min.extend(vec![(1, 'd'), (4, 'e')]);
+        assert_eq!(min.get(), Some(&(1, 'b')));
+
+        min.merge(vec![(0, 'f')].into_iter().collect());
+        assert_eq!(min.finish(), Some((0, 'f')));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::common::*;
+
+/// A collection that computes the minimum `f32` or `f64` value.
+///
+/// NaN values are treated according to the policy `P`, which defaults to
+/// [`IgnoreNan`]. The number of NaN values seen is counted regardless of the policy.
+///
+/// ```rust
+/// use collected::{MinF, PropagateNan};
+///
+/// let min: MinF<f64> = vec![1.0, f64::NAN, -3.0].into_iter().collect();
+/// assert_eq!(min.get(), Some(-3.0));
+/// assert_eq!(min.nan_count(), 1);
+///
+/// let min: MinF<f64, PropagateNan> = vec![1.0, f64::NAN, -3.0].into_iter().collect();
+/// assert!(min.unwrap().is_nan());
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinF<A, P = IgnoreNan> {
+    min: Option<A>,
+    nan_count: usize,
+    _phantom: PhantomData<P>,
+}
+
+impl<A, P> MinF<A, P> {
+    pub fn unwrap(self) -> A {
+        self.min.unwrap()
+    }
+
+    pub fn get(&self) -> Option<A>
+    where
+        A: Copy,
+    {
+        self.min
+    }
+
+    pub fn into_inner(self) -> Option<A> {
+        self.min
+    }
+
+    /// Returns the number of NaN values seen.
+    pub fn nan_count(&self) -> usize {
+        self.nan_count
+    }
+}
+
+impl<A, P> MinF<A, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    fn push_value(&mut self, item: A) {
+        let min = match self.min {
+            None => item,
+            Some(min) if P::PROPAGATE_NAN && min.is_nan() => min,
+            Some(_) if P::PROPAGATE_NAN && item.is_nan() => item,
+            Some(min) => cmp::min_by(min, item, P::compare),
+        };
+        self.min = Some(min);
+    }
+}
+
+impl<A, P> Default for MinF<A, P> {
+    fn default() -> Self {
+        Self {
+            min: None,
+            nan_count: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, P> From<MinF<A, P>> for Option<A> {
+    fn from(collector: MinF<A, P>) -> Self {
+        collector.min
+    }
+}
+
+impl<A, P> FromIterator<A> for MinF<A, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut min = Self::default();
+        min.extend(iter);
+        min
+    }
+}
+
+impl<A, P> Extend<A> for MinF<A, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, P> Collector for MinF<A, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    type Item = A;
+    type Output = Option<A>;
+
+    fn push(&mut self, item: A) {
+        if item.is_nan() {
+            self.nan_count += 1;
+            if P::SKIP_NAN {
+                return;
+            }
+        }
+        self.push_value(item);
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.nan_count += other.nan_count;
+        if let Some(min) = other.min {
+            self.push_value(min);
+        }
+    }
+
+    fn finish(self) -> Option<A> {
+        self.min
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, P> FromParallelIterator<A> for MinF<A, P>
+where
+    A: Float + Send,
+    P: NanPolicy + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, P> ParallelExtend<A> for MinF<A, P>
+where
+    A: Float + Send,
+    P: NanPolicy + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NanGreatest, PropagateNan, TotalOrder};
+
+    #[test]
+    fn min_f_test() {
+        let values = || vec![1.0, f64::NAN, -3.0, -f64::NAN, 2.0];
+
+        let min: MinF<f64> = values().into_iter().collect();
+        assert_eq!((min.get(), min.nan_count()), (Some(-3.0), 2));
+
+        let min: MinF<f64, PropagateNan> = values().into_iter().collect();
+        assert!(min.unwrap().is_nan());
+
+        let min: MinF<f64, NanGreatest> = values().into_iter().collect();
+        assert_eq!(min.get(), Some(-3.0));
+
+        let min: MinF<f64, TotalOrder> = values().into_iter().collect();
+        assert!(min.unwrap().is_nan());
+
+        let min: MinF<f32> = vec![f32::NAN].into_iter().collect();
+        assert_eq!((min.get(), min.nan_count()), (None, 1));
+    }
+
+    #[test]
+    fn min_f_merge_test() {
+        let mut lhs: MinF<f64> = vec![1.0, f64::NAN].into_iter().collect();
+        lhs.merge(vec![f64::NAN, 0.5, 4.0].into_iter().collect());
+        assert_eq!((lhs.get(), lhs.nan_count()), (Some(0.5), 2));
+
+        let mut lhs: MinF<f64, PropagateNan> = vec![1.0].into_iter().collect();
+        lhs.merge(vec![f64::NAN, -4.0].into_iter().collect());
+        assert!(lhs.finish().unwrap().is_nan());
+    }
+}
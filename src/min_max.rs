@@ -0,0 +1,248 @@
+use crate::common::*;
+
+/// The result of [`MinMaxVal`], which is either empty, one element or a pair of
+/// minimum and maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MinMaxResult<A> {
+    #[default]
+    NoElements,
+    OneElement(A),
+    MinMax(A, A),
+}
+
+impl<A> MinMaxResult<A> {
+    /// Returns the pair of minimum and maximum, where both are the same element
+    /// if only one element is collected.
+    pub fn into_option(self) -> Option<(A, A)>
+    where
+        A: Clone,
+    {
+        match self {
+            Self::NoElements => None,
+            Self::OneElement(value) => Some((value.clone(), value)),
+            Self::MinMax(min, max) => Some((min, max)),
+        }
+    }
+}
+
+/// A collection that computes both the minimum and maximum values.
+///
+/// Items are compared in pairs, taking about 1.5 comparisons per item. Like
+/// [`MinVal`](crate::MinVal) and [`MaxVal`](crate::MaxVal), it keeps the first
+/// minimum and the last maximum among equal values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MinMaxVal<A>(MinMaxResult<A>);
+
+impl<A> MinMaxVal<A> {
+    pub fn get(&self) -> &MinMaxResult<A> {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> MinMaxResult<A> {
+        self.0
+    }
+}
+
+impl<A> MinMaxVal<A>
+where
+    A: Ord,
+{
+    /// Pushes two consecutive items with one comparison between them.
+    fn push_pair(&mut self, first: A, second: A) {
+        if second < first {
+            self.push_bounds(second, first);
+        } else {
+            self.push_bounds(first, second);
+        }
+    }
+
+    /// Pushes the bounds `small <= large` of subsequent items.
+    fn push_bounds(&mut self, small: A, large: A) {
+        self.0 = match mem::take(&mut self.0) {
+            MinMaxResult::NoElements => MinMaxResult::MinMax(small, large),
+            MinMaxResult::OneElement(value) => {
+                if small >= value {
+                    MinMaxResult::MinMax(value, large)
+                } else if large >= value {
+                    MinMaxResult::MinMax(small, large)
+                } else {
+                    MinMaxResult::MinMax(small, value)
+                }
+            }
+            MinMaxResult::MinMax(min, max) => {
+                let min = if small < min { small } else { min };
+                let max = if large >= max { large } else { max };
+                MinMaxResult::MinMax(min, max)
+            }
+        };
+    }
+}
+
+impl<A> Default for MinMaxVal<A> {
+    fn default() -> Self {
+        Self(MinMaxResult::NoElements)
+    }
+}
+
+impl<A> From<MinMaxVal<A>> for MinMaxResult<A> {
+    fn from(collector: MinMaxVal<A>) -> Self {
+        collector.0
+    }
+}
+
+impl<A> FromIterator<A> for MinMaxVal<A>
+where
+    A: Ord,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut minmax = Self::default();
+        minmax.extend(iter);
+        minmax
+    }
+}
+
+impl<A> Extend<A> for MinMaxVal<A>
+where
+    A: Ord,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        let mut iter = iter.into_iter();
+        while let Some(first) = iter.next() {
+            match iter.next() {
+                Some(second) => self.push_pair(first, second),
+                None => self.push(first),
+            }
+        }
+    }
+}
+
+impl<A> Collector for MinMaxVal<A>
+where
+    A: Ord,
+{
+    type Item = A;
+    type Output = MinMaxResult<A>;
+
+    fn push(&mut self, item: A) {
+        self.0 = match mem::take(&mut self.0) {
+            MinMaxResult::NoElements => MinMaxResult::OneElement(item),
+            MinMaxResult::OneElement(value) => {
+                if item < value {
+                    MinMaxResult::MinMax(item, value)
+                } else {
+                    MinMaxResult::MinMax(value, item)
+                }
+            }
+            MinMaxResult::MinMax(min, max) => {
+                if item < min {
+                    MinMaxResult::MinMax(item, max)
+                } else if item >= max {
+                    MinMaxResult::MinMax(min, item)
+                } else {
+                    MinMaxResult::MinMax(min, max)
+                }
+            }
+        };
+    }
+
+    fn merge(&mut self, other: Self) {
+        match other.0 {
+            MinMaxResult::NoElements => {}
+            MinMaxResult::OneElement(value) => self.push(value),
+            MinMaxResult::MinMax(min, max) => self.push_bounds(min, max),
+        }
+    }
+
+    fn finish(self) -> MinMaxResult<A> {
+        self.0
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for MinMaxVal<A>
+where
+    A: Ord + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for MinMaxVal<A>
+where
+    A: Ord + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_max_test() {
+        let minmax: MinMaxVal<usize> = iter::empty().collect();
+        assert_eq!(minmax.into_inner(), MinMaxResult::NoElements);
+
+        let mut minmax: MinMaxVal<usize> = iter::once(5).collect();
+        assert_eq!(minmax.get(), &MinMaxResult::OneElement(5));
+
+        minmax.extend(vec![3, 8, 1, 9, 4, 2]);
+        assert_eq!(minmax.get(), &MinMaxResult::MinMax(1, 9));
+
+        minmax.push(0);
+        assert_eq!(minmax.into_inner().into_option(), Some((0, 9)));
+
+        let values = vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (2, 'e')];
+        let minmax: MinMaxVal<Key> = values.into_iter().map(Key).collect();
+        assert_eq!(
+            minmax.into_inner(),
+            MinMaxResult::MinMax(Key((1, 'b')), Key((2, 'e')))
+        );
+    }
+
+    #[test]
+    fn min_max_merge_test() {
+        let mut lhs: MinMaxVal<usize> = iter::once(5).collect();
+        lhs.merge(vec![2, 7].into_iter().collect());
+        assert_eq!(lhs.get(), &MinMaxResult::MinMax(2, 7));
+
+        lhs.merge(iter::once(1).collect());
+        lhs.merge(MinMaxVal::default());
+        assert_eq!(lhs.finish(), MinMaxResult::MinMax(1, 7));
+
+        let mut lhs: MinMaxVal<Key> = iter::once(Key((1, 'a'))).collect();
+        lhs.merge(vec![Key((1, 'b')), Key((1, 'c'))].into_iter().collect());
+        assert_eq!(
+            lhs.finish(),
+            MinMaxResult::MinMax(Key((1, 'a')), Key((1, 'c')))
+        );
+    }
+
+    /// Compares by the first element only, to tell apart equal values.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Key((usize, char));
+
+    impl PartialOrd for Key {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Key {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            (self.0).0.cmp(&(other.0).0)
+        }
+    }
+}
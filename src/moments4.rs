@@ -0,0 +1,228 @@
+use crate::common::*;
+
+/// A collection that computes the first four central moments of integer or float values.
+///
+/// It keeps the count, the mean and the sums of the 2nd, 3rd and 4th powers of
+/// deviations, updated in one pass by Terriberry's formulas. Partial states are
+/// merged by Pébay's formulas. Besides the mean and the variance, it provides
+/// the skewness and the excess kurtosis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Moments4<A> {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    m3: f64,
+    m4: f64,
+    _phantom: PhantomData<A>,
+}
+
+impl<A> Moments4<A> {
+    /// Returns the number of collected values.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean, or `None` if no values are collected.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the population variance, or `None` if no values are collected.
+    pub fn population_variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// Returns the sample variance, or `None` if less than two values are collected.
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+
+    /// Returns the population skewness `g1 = sqrt(n) * M3 / M2^1.5`, or `None`
+    /// if no values are collected or all values are equal.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn skewness(&self) -> Option<f64> {
+        (self.count > 0 && self.m2 > 0.0).then(|| {
+            let n = self.count as f64;
+            math::sqrt(n) * self.m3 / (self.m2 * math::sqrt(self.m2))
+        })
+    }
+
+    /// Returns the population excess kurtosis `g2 = n * M4 / M2^2 - 3`, or
+    /// `None` if no values are collected or all values are equal.
+    pub fn excess_kurtosis(&self) -> Option<f64> {
+        (self.count > 0 && self.m2 > 0.0)
+            .then(|| self.count as f64 * self.m4 / (self.m2 * self.m2) - 3.0)
+    }
+}
+
+impl<A> Default for Moments4<A> {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            m3: 0.0,
+            m4: 0.0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A> FromIterator<A> for Moments4<A>
+where
+    A: ToF64,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut moments = Self::default();
+        moments.extend(iter);
+        moments
+    }
+}
+
+impl<A> Extend<A> for Moments4<A>
+where
+    A: ToF64,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A> Collector for Moments4<A>
+where
+    A: ToF64,
+{
+    type Item = A;
+    type Output = Self;
+
+    fn push(&mut self, item: A) {
+        let value = item.to_f64();
+        let prev_count = self.count as f64;
+        self.count += 1;
+        let n = self.count as f64;
+
+        let delta = value - self.mean;
+        let delta_n = delta / n;
+        let delta_n2 = delta_n * delta_n;
+        let term = delta * delta_n * prev_count;
+
+        self.mean += delta_n;
+        self.m4 += term * delta_n2 * (n * n - 3.0 * n + 3.0) + 6.0 * delta_n2 * self.m2
+            - 4.0 * delta_n * self.m3;
+        self.m3 += term * delta_n * (n - 2.0) - 3.0 * delta_n * self.m2;
+        self.m2 += term;
+    }
+
+    fn merge(&mut self, other: Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other;
+            return;
+        }
+
+        let na = self.count as f64;
+        let nb = other.count as f64;
+        let n = na + nb;
+        let delta = other.mean - self.mean;
+        let delta2 = delta * delta;
+
+        let m2 = self.m2 + other.m2 + delta2 * na * nb / n;
+        let m3 = self.m3
+            + other.m3
+            + delta2 * delta * na * nb * (na - nb) / (n * n)
+            + 3.0 * delta * (na * other.m2 - nb * self.m2) / n;
+        let m4 = self.m4
+            + other.m4
+            + delta2 * delta2 * na * nb * (na * na - na * nb + nb * nb) / (n * n * n)
+            + 6.0 * delta2 * (na * na * other.m2 + nb * nb * self.m2) / (n * n)
+            + 4.0 * delta * (na * other.m3 - nb * self.m3) / n;
+
+        self.count += other.count;
+        self.mean += delta * nb / n;
+        self.m2 = m2;
+        self.m3 = m3;
+        self.m4 = m4;
+    }
+
+    fn finish(self) -> Self {
+        self
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for Moments4<A>
+where
+    A: ToF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for Moments4<A>
+where
+    A: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn moments4_test() {
+        let mut moments: Moments4<i32> = vec![2, 4, 4, 4].into_iter().collect();
+        moments.extend(vec![5, 5, 7, 9]);
+        assert_eq!(moments.count(), 8);
+        assert_eq!(moments.mean(), Some(5.0));
+        assert_close(moments.population_variance().unwrap(), 4.0);
+        assert_close(moments.excess_kurtosis().unwrap(), -0.21875);
+        #[cfg(any(feature = "std", feature = "libm"))]
+        assert_close(moments.skewness().unwrap(), 0.65625);
+
+        let moments: Moments4<f64> = vec![3.0, 3.0].into_iter().collect();
+        assert_eq!(moments.excess_kurtosis(), None);
+
+        let moments: Moments4<f64> = iter::empty().collect();
+        assert_eq!(moments.mean(), None);
+    }
+
+    #[test]
+    fn moments4_merge_test() {
+        let values: Vec<f64> = (0..200)
+            .map(|val| ((val * val) % 37) as f64 * 0.5)
+            .collect();
+        let whole: Moments4<f64> = values.iter().copied().collect();
+
+        let mut merged: Moments4<f64> = values[..70].iter().copied().collect();
+        merged.merge(values[70..71].iter().copied().collect());
+        merged.merge(values[71..].iter().copied().collect());
+        merged.merge(Moments4::default());
+
+        assert_eq!(merged.count(), whole.count());
+        assert_close(merged.mean().unwrap(), whole.mean().unwrap());
+        assert_close(
+            merged.population_variance().unwrap(),
+            whole.population_variance().unwrap(),
+        );
+        assert_close(merged.m3, whole.m3);
+        assert_close(
+            merged.excess_kurtosis().unwrap(),
+            whole.excess_kurtosis().unwrap(),
+        );
+    }
+}
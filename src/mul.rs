@@ -2,6 +2,7 @@ use crate::common::*;
 
 /// A collection that multiplies values with [`Mul`](Mul) trait.
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct MulVal<A>(Option<A>);
 
 impl<A> FromIterator<A> for MulVal<A>
@@ -9,7 +10,7 @@ where
     A: Mul<A, Output = A>,
 {
     fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
-        let prod = iter.into_iter().fold1(|lhs, rhs| lhs * rhs);
+        let prod = iter.into_iter().reduce(|lhs, rhs| lhs * rhs);
         Self(prod)
     }
 }
@@ -44,11 +45,63 @@ where
             .take()
             .into_iter()
             .chain(iter)
-            .fold1(|lhs, rhs| lhs * rhs);
+            .reduce(|lhs, rhs| lhs * rhs);
         self.0 = prod;
     }
 }
 
+impl<A> Collector for MulVal<A>
+where
+    A: Mul<A, Output = A>,
+{
+    type Item = A;
+    type Output = Option<A>;
+
+    fn push(&mut self, item: A) {
+        let prod = match self.0.take() {
+            Some(lhs) => lhs * item,
+            None => item,
+        };
+        self.0 = Some(prod);
+    }
+
+    fn merge(&mut self, other: Self) {
+        if let Some(rhs) = other.0 {
+            self.push(rhs);
+        }
+    }
+
+    fn finish(self) -> Option<A> {
+        self.0
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for MulVal<A>
+where
+    A: Mul<A, Output = A> + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for MulVal<A>
+where
+    A: Mul<A, Output = A> + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,77 @@
+use crate::common::*;
+
+/// Selects how float collections such as [`MaxF`](crate::MaxF) treat NaN values.
+pub trait NanPolicy {
+    /// Whether NaN values are skipped.
+    const SKIP_NAN: bool;
+
+    /// Whether the extreme becomes NaN once a NaN value is seen.
+    const PROPAGATE_NAN: bool;
+
+    /// Compares two values that are not skipped.
+    fn compare<A: Float>(lhs: &A, rhs: &A) -> Ordering;
+}
+
+/// Skips NaN values.
+///
+/// NaN values never reach the comparison when pushed. Should one be compared
+/// anyway, such as from deserialized state, it is ordered as the greatest value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct IgnoreNan;
+
+impl NanPolicy for IgnoreNan {
+    const SKIP_NAN: bool = true;
+    const PROPAGATE_NAN: bool = false;
+
+    fn compare<A: Float>(lhs: &A, rhs: &A) -> Ordering {
+        NanGreatest::compare(lhs, rhs)
+    }
+}
+
+/// Makes the maximum and the minimum NaN once a NaN value is seen.
+///
+/// Collections that order values, such as [`TopKF`](crate::TopKF), order NaN
+/// as the greatest value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PropagateNan;
+
+impl NanPolicy for PropagateNan {
+    const SKIP_NAN: bool = false;
+    const PROPAGATE_NAN: bool = true;
+
+    fn compare<A: Float>(lhs: &A, rhs: &A) -> Ordering {
+        NanGreatest::compare(lhs, rhs)
+    }
+}
+
+/// Orders NaN as greater than every other value, including infinity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NanGreatest;
+
+impl NanPolicy for NanGreatest {
+    const SKIP_NAN: bool = false;
+    const PROPAGATE_NAN: bool = false;
+
+    fn compare<A: Float>(lhs: &A, rhs: &A) -> Ordering {
+        match (lhs.is_nan(), rhs.is_nan()) {
+            (false, false) => lhs.partial_cmp(rhs).unwrap(),
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (true, true) => Ordering::Equal,
+        }
+    }
+}
+
+/// Orders values by [`f64::total_cmp()`], where positive NaN is the greatest and
+/// negative NaN is the smallest value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct TotalOrder;
+
+impl NanPolicy for TotalOrder {
+    const SKIP_NAN: bool = false;
+    const PROPAGATE_NAN: bool = false;
+
+    fn compare<A: Float>(lhs: &A, rhs: &A) -> Ordering {
+        lhs.total_cmp(rhs)
+    }
+}
@@ -0,0 +1,126 @@
+use crate::common::*;
+
+/// A collection that sums up `f32` or `f64` values by the Kahan–Babuška–Neumaier algorithm.
+///
+/// Unlike [`KahanSum`](crate::KahanSum), it also compensates the error when the
+/// incoming value is larger in magnitude than the running sum. The compensation
+/// term is carried across [`extend()`](Extend::extend) calls and merges.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NeumaierSum<A> {
+    sum: A,
+    compensation: A,
+}
+
+impl<A> NeumaierSum<A> {
+    /// Returns the running compensation term.
+    pub fn compensation(&self) -> &A {
+        &self.compensation
+    }
+}
+
+macro_rules! impl_neumaier_sum {
+    ($ty:ty) => {
+        impl NeumaierSum<$ty> {
+            /// Returns the compensated sum.
+            pub fn get(&self) -> $ty {
+                self.sum + self.compensation
+            }
+        }
+
+        impl From<NeumaierSum<$ty>> for $ty {
+            fn from(collector: NeumaierSum<$ty>) -> Self {
+                collector.get()
+            }
+        }
+
+        impl FromIterator<$ty> for NeumaierSum<$ty> {
+            fn from_iter<T: IntoIterator<Item = $ty>>(iter: T) -> Self {
+                let mut sum = Self::default();
+                sum.extend(iter);
+                sum
+            }
+        }
+
+        impl Extend<$ty> for NeumaierSum<$ty> {
+            fn extend<T: IntoIterator<Item = $ty>>(&mut self, iter: T) {
+                iter.into_iter().for_each(|item| self.push(item));
+            }
+        }
+
+        impl Collector for NeumaierSum<$ty> {
+            type Item = $ty;
+            type Output = $ty;
+
+            fn push(&mut self, item: $ty) {
+                let abs = |value: $ty| if value < 0.0 { -value } else { value };
+
+                let sum = self.sum + item;
+                self.compensation += if abs(self.sum) >= abs(item) {
+                    (self.sum - sum) + item
+                } else {
+                    (item - sum) + self.sum
+                };
+                self.sum = sum;
+            }
+
+            fn merge(&mut self, other: Self) {
+                self.push(other.sum);
+                self.compensation += other.compensation;
+            }
+
+            fn finish(self) -> $ty {
+                self.get()
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl FromParallelIterator<$ty> for NeumaierSum<$ty> {
+            fn from_par_iter<I>(par_iter: I) -> Self
+            where
+                I: IntoParallelIterator<Item = $ty>,
+            {
+                par::collect(par_iter)
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl ParallelExtend<$ty> for NeumaierSum<$ty> {
+            fn par_extend<I>(&mut self, par_iter: I)
+            where
+                I: IntoParallelIterator<Item = $ty>,
+            {
+                par::extend(self, par_iter);
+            }
+        }
+    };
+}
+
+impl_neumaier_sum!(f32);
+impl_neumaier_sum!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neumaier_sum_test() {
+        let values = || vec![1.0, 1e100, 1.0, -1e100];
+        assert_eq!(values().into_iter().sum::<f64>(), 0.0);
+
+        let sum: NeumaierSum<f64> = values().into_iter().collect();
+        assert_eq!(sum.get(), 2.0);
+
+        let mut sum: NeumaierSum<f64> = values()[..2].iter().copied().collect();
+        sum.extend(values()[2..].iter().copied());
+        assert_eq!(sum.get(), 2.0);
+
+        let mut lhs: NeumaierSum<f64> = values()[..1].iter().copied().collect();
+        lhs.merge(values()[1..3].iter().copied().collect());
+        lhs.merge(values()[3..].iter().copied().collect());
+        assert_eq!(lhs.finish(), 2.0);
+
+        let sum: NeumaierSum<f32> = vec![1.0, 1e20, 1.0, -1e20].into_iter().collect();
+        assert_eq!(sum.get(), 2.0);
+    }
+}
@@ -4,6 +4,7 @@ use crate::common::*;
 ///
 /// By collecting/extending an iterator into `Noop`, each item is consumed and dropped.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Noop<T> {
     _phantom: PhantomData<T>,
 }
@@ -31,6 +32,43 @@ impl<A> Extend<A> for Noop<A> {
     }
 }
 
+impl<A> Collector for Noop<A> {
+    type Item = A;
+    type Output = ();
+
+    fn push(&mut self, _item: A) {}
+
+    fn merge(&mut self, _other: Self) {}
+
+    fn finish(self) {}
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for Noop<A>
+where
+    A: Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for Noop<A>
+where
+    A: Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,134 @@
+use core::cmp::Ordering;
+
+/// Primitive numeric types that statistical collections convert to `f64`.
+pub trait ToF64: Copy {
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_to_f64 {
+    ($($ty:ty),*) => {
+        $(
+            impl ToF64 for $ty {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_to_f64!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Primitive numeric types that convert to `f64` without rounding, namely `f32`,
+/// `f64` and integers up to 32 bits.
+pub trait ExactF64: ToF64 {}
+
+impl ExactF64 for u8 {}
+impl ExactF64 for u16 {}
+impl ExactF64 for u32 {}
+impl ExactF64 for i8 {}
+impl ExactF64 for i16 {}
+impl ExactF64 for i32 {}
+impl ExactF64 for f32 {}
+impl ExactF64 for f64 {}
+
+/// Float functions that are only provided by `std` or `libm`.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) mod math {
+    #[cfg(feature = "std")]
+    pub fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn sqrt(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn ln(x: f64) -> f64 {
+        x.ln()
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn ln(x: f64) -> f64 {
+        libm::log(x)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn exp(x: f64) -> f64 {
+        x.exp()
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn exp(x: f64) -> f64 {
+        libm::exp(x)
+    }
+}
+
+/// The floating point types `f32` and `f64`.
+pub trait Float: Copy + PartialOrd {
+    fn is_nan(self) -> bool;
+
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+macro_rules! impl_float {
+    ($ty:ty) => {
+        impl Float for $ty {
+            fn is_nan(self) -> bool {
+                <$ty>::is_nan(self)
+            }
+
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                <$ty>::total_cmp(self, other)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+/// The primitive integer types.
+pub trait Integer: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    fn wrapping_add(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl Integer for $ty {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$ty>::checked_add(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$ty>::checked_mul(self, rhs)
+                }
+
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$ty>::saturating_add(self, rhs)
+                }
+
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_add(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,96 @@
+//! Helpers to drive collectors from rayon parallel iterators.
+//!
+//! Each rayon job collects its share of items into a partial collector, and
+//! the partial collectors are merged in order by [`Collector::merge`].
+
+use crate::common::*;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+/// Collects a parallel iterator into a collector.
+pub(crate) fn collect<C, I>(par_iter: I) -> C
+where
+    C: Collector + FromIterator<C::Item> + Send,
+    C::Item: Send,
+    I: IntoParallelIterator<Item = C::Item>,
+{
+    par_iter
+        .into_par_iter()
+        .fold(
+            || None,
+            |partial: Option<C>, item| match partial {
+                Some(mut partial) => {
+                    partial.push(item);
+                    Some(partial)
+                }
+                None => Some(iter::once(item).collect()),
+            },
+        )
+        .reduce(
+            || None,
+            |lhs, rhs| match (lhs, rhs) {
+                (Some(mut lhs), Some(rhs)) => {
+                    lhs.merge(rhs);
+                    Some(lhs)
+                }
+                (lhs, None) => lhs,
+                (None, rhs) => rhs,
+            },
+        )
+        .unwrap_or_else(|| iter::empty().collect())
+}
+
+/// Extends a collector with items from a parallel iterator.
+pub(crate) fn extend<C, I>(collector: &mut C, par_iter: I)
+where
+    C: Collector + FromIterator<C::Item> + Send,
+    C::Item: Send,
+    I: IntoParallelIterator<Item = C::Item>,
+{
+    collector.merge(collect(par_iter));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{First, GroupHashMap, LastN, MaxVal, SumVal, TopK, UniqueHashSet};
+    use rayon::prelude::*;
+
+    #[test]
+    fn par_collect_test() {
+        let sum: SumVal<usize> = (1..=1000).into_par_iter().collect();
+        assert_eq!(sum.into_inner(), 500500);
+
+        let max: MaxVal<usize> = (0..1000).into_par_iter().collect();
+        assert_eq!(max.unwrap(), 999);
+
+        let first: First<usize> = (0..1000).into_par_iter().collect();
+        assert_eq!(first.into_inner(), Some(0));
+
+        let last_n: LastN<Vec<_>, usize, 3> = (0..1000).into_par_iter().collect();
+        assert_eq!(last_n.into_inner(), vec![997, 998, 999]);
+
+        let topk: TopK<usize, 3> = (0..1000).into_par_iter().collect();
+        assert_eq!(topk.into_sorted_vec(), vec![999, 998, 997]);
+
+        let map: GroupHashMap<usize, usize> = (0..1000)
+            .into_par_iter()
+            .map(|val| (val % 2, val))
+            .collect();
+        let map = map.into_inner();
+        assert_eq!(map[&0], (0..1000).step_by(2).collect::<Vec<_>>());
+        assert_eq!(map[&1], (1..1000).step_by(2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn par_extend_test() {
+        let mut sum: SumVal<usize> = (1..=100).collect();
+        sum.par_extend(1..=100);
+        assert_eq!(sum.into_inner(), 10100);
+
+        let mut set: UniqueHashSet<usize> = (0..1000).into_par_iter().collect();
+        assert_eq!(set.get().map(|set| set.len()), Some(1000));
+        set.par_extend(1000..2000);
+        assert_eq!(set.get().map(|set| set.len()), Some(2000));
+        set.par_extend(vec![0]);
+        assert_eq!(set.get(), None);
+    }
+}
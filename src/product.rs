@@ -2,6 +2,7 @@ use crate::common::*;
 
 /// A collection taking the product from values with [`Product`](Product) trait.
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ProductVal<A>(pub A);
 
 impl<A> FromIterator<A> for ProductVal<A>
@@ -37,6 +38,52 @@ where
     }
 }
 
+impl<A> Collector for ProductVal<A>
+where
+    A: Product<A>,
+{
+    type Item = A;
+    type Output = A;
+
+    fn push(&mut self, item: A) {
+        self.extend(iter::once(item));
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.extend(iter::once(other.0));
+    }
+
+    fn finish(self) -> A {
+        self.0
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for ProductVal<A>
+where
+    A: Product<A> + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for ProductVal<A>
+where
+    A: Product<A> + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
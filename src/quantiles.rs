@@ -0,0 +1,228 @@
+use crate::common::*;
+
+/// The interpolation method for [`Quantiles`] when the quantile lies between two
+/// values, following the methods of the same names in numpy.
+///
+/// For `n` sorted values, the quantile `q` lies at the fractional position
+/// `(n - 1) * q`, which is between the `lower` and the `higher` positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Interpolation {
+    /// Linearly interpolates between the lower and the higher values.
+    #[default]
+    Linear,
+    /// Takes the value at the lower position.
+    Lower,
+    /// Takes the value at the higher position.
+    Higher,
+    /// Takes the value at the nearest position, rounding half to even.
+    Nearest,
+    /// Takes the mean of the lower and the higher values.
+    Midpoint,
+}
+
+/// A collection that buffers values and answers exact quantile queries.
+///
+/// A query selects the needed values in linear time rather than sorting the
+/// buffer. The query may reorder the buffer, so that it takes `&mut self`, and
+/// the collection can be extended afterwards. Values are ordered by
+/// [`f64::total_cmp()`].
+///
+/// Values are buffered as `f64`. To keep the quantiles exact, the item type is
+/// limited to [`ExactF64`] types, which `f64` represents without rounding.
+///
+/// ```rust
+/// use collected::{Interpolation, Quantiles};
+///
+/// let mut latencies: Quantiles<u32> = (1..=100).collect();
+/// assert_eq!(latencies.median(), Some(50.5));
+/// assert_eq!(latencies.quantile(0.9, Interpolation::Lower), Some(90.0));
+///
+/// latencies.extend(vec![1000; 10]);
+/// assert_eq!(latencies.quantile(0.99, Interpolation::Higher), Some(1000.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Quantiles<A> {
+    values: Vec<f64>,
+    _phantom: PhantomData<A>,
+}
+
+impl<A> Quantiles<A> {
+    /// Returns the number of collected values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the buffered values in unspecified order.
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// Returns the median, or `None` if no values are collected.
+    pub fn median(&mut self) -> Option<f64> {
+        self.quantile(0.5, Interpolation::Linear)
+    }
+
+    /// Returns the quantile `q` computed by the interpolation `method`, or
+    /// `None` if no values are collected.
+    ///
+    /// # Panics
+    /// Panics if `q` is not within `[0, 1]`.
+    pub fn quantile(&mut self, q: f64, method: Interpolation) -> Option<f64> {
+        assert!(
+            (0.0..=1.0).contains(&q),
+            "the quantile must be within [0, 1]"
+        );
+        if self.values.is_empty() {
+            return None;
+        }
+
+        let pos = (self.values.len() - 1) as f64 * q;
+        let lower = pos as usize;
+        let frac = pos - lower as f64;
+        let higher = if frac > 0.0 { lower + 1 } else { lower };
+
+        let (_, &mut lower_value, rest) = self.values.select_nth_unstable_by(lower, f64::total_cmp);
+        let higher_value = if higher > lower {
+            rest.iter().copied().min_by(f64::total_cmp).unwrap()
+        } else {
+            lower_value
+        };
+
+        let value = match method {
+            Interpolation::Linear => lower_value + (higher_value - lower_value) * frac,
+            Interpolation::Lower => lower_value,
+            Interpolation::Higher => higher_value,
+            Interpolation::Nearest => {
+                if frac < 0.5 || (frac == 0.5 && lower % 2 == 0) {
+                    lower_value
+                } else {
+                    higher_value
+                }
+            }
+            Interpolation::Midpoint => (lower_value + higher_value) / 2.0,
+        };
+        Some(value)
+    }
+}
+
+impl<A> Default for Quantiles<A> {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A> FromIterator<A> for Quantiles<A>
+where
+    A: ExactF64,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut quantiles = Self::default();
+        quantiles.extend(iter);
+        quantiles
+    }
+}
+
+impl<A> Extend<A> for Quantiles<A>
+where
+    A: ExactF64,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        self.values.extend(iter.into_iter().map(A::to_f64));
+    }
+}
+
+impl<A> Collector for Quantiles<A>
+where
+    A: ExactF64,
+{
+    type Item = A;
+    type Output = Self;
+
+    fn push(&mut self, item: A) {
+        self.values.push(item.to_f64());
+    }
+
+    fn merge(&mut self, mut other: Self) {
+        self.values.append(&mut other.values);
+    }
+
+    fn finish(self) -> Self {
+        self
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for Quantiles<A>
+where
+    A: ExactF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for Quantiles<A>
+where
+    A: ExactF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantiles_test() {
+        use Interpolation::*;
+
+        let mut quantiles: Quantiles<i32> = vec![4, 1, 3, 2].into_iter().collect();
+        let query = |quantiles: &mut Quantiles<i32>, q| {
+            [Linear, Lower, Higher, Nearest, Midpoint]
+                .map(|method| quantiles.quantile(q, method).unwrap())
+        };
+
+        assert_eq!(query(&mut quantiles, 0.4), [2.2, 2.0, 3.0, 2.0, 2.5]);
+        assert_eq!(query(&mut quantiles, 0.5), [2.5, 2.0, 3.0, 3.0, 2.5]);
+        assert_eq!(query(&mut quantiles, 0.0), [1.0; 5]);
+        assert_eq!(query(&mut quantiles, 1.0), [4.0; 5]);
+
+        quantiles.extend(vec![0, 10, 5]);
+        assert_eq!(quantiles.len(), 7);
+        assert_eq!(quantiles.median(), Some(3.0));
+        assert_eq!(quantiles.quantile(0.25, Linear), Some(1.5));
+
+        let mut empty: Quantiles<f64> = iter::empty().collect();
+        assert_eq!(empty.median(), None);
+    }
+
+    #[test]
+    fn quantiles_merge_test() {
+        let mut lhs: Quantiles<f64> = vec![5.0, 1.0].into_iter().collect();
+        lhs.median();
+        lhs.merge(vec![3.0, 2.0, 4.0].into_iter().collect());
+        lhs.push(0.0);
+
+        let mut quantiles = lhs.finish();
+        assert_eq!(quantiles.median(), Some(2.5));
+        assert_eq!(quantiles.quantile(0.8, Interpolation::Nearest), Some(4.0));
+    }
+}
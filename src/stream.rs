@@ -0,0 +1,135 @@
+//! Collecting [`Stream`]s into collectors.
+
+use crate::common::*;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::{pin_mut, ready, stream::Stream, StreamExt};
+use pin_project_lite::pin_project;
+
+/// An extension trait that collects [`Stream`]s into [`Collector`]s.
+///
+/// ```rust
+/// use collected::{StreamCollectedExt, TopK};
+/// use futures::{executor::block_on, stream};
+///
+/// let topk: TopK<_, 2> = block_on(stream::iter(vec![3, 1, 4, 1, 5]).collected());
+/// assert_eq!(topk.into_sorted_vec(), vec![5, 4]);
+/// ```
+pub trait StreamCollectedExt: Stream {
+    /// Collects items into a collector until the stream ends or the collector is saturated.
+    fn collected<C>(self) -> Collected<Self, C>
+    where
+        Self: Sized,
+        C: Collector<Item = Self::Item> + Default,
+    {
+        Collected {
+            stream: self,
+            collector: Some(C::default()),
+        }
+    }
+}
+
+impl<S> StreamCollectedExt for S where S: Stream {}
+
+pin_project! {
+    /// The future returned by [`collected()`](StreamCollectedExt::collected).
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct Collected<S, C> {
+        #[pin]
+        stream: S,
+        collector: Option<C>,
+    }
+}
+
+impl<S, C> Future for Collected<S, C>
+where
+    S: Stream,
+    C: Collector<Item = S::Item>,
+{
+    type Output = C;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<C> {
+        let mut this = self.project();
+
+        loop {
+            let collector = this
+                .collector
+                .as_mut()
+                .expect("Collected must not be polled after it returned `Poll::Ready`");
+
+            if collector.is_done() {
+                break;
+            }
+
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Some(item) => collector.push(item),
+                None => break,
+            }
+        }
+
+        Poll::Ready(this.collector.take().unwrap())
+    }
+}
+
+/// Extends a collector with items from a stream until the stream ends or the
+/// collector is saturated.
+///
+/// ```rust
+/// use collected::{extend_from_stream, SumVal};
+/// use futures::{executor::block_on, stream};
+///
+/// let mut sum: SumVal<usize> = (1..=3).collect();
+/// block_on(extend_from_stream(&mut sum, stream::iter(4..=5)));
+/// assert_eq!(sum.into_inner(), 15);
+/// ```
+pub async fn extend_from_stream<C, S>(collector: &mut C, stream: S)
+where
+    C: Collector,
+    S: Stream<Item = C::Item>,
+{
+    pin_mut!(stream);
+
+    while !collector.is_done() {
+        match stream.next().await {
+            Some(item) => collector.push(item),
+            None => break,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{First, LastN, MaxVal};
+    use futures::{executor::block_on, stream};
+
+    #[test]
+    fn stream_collected_test() {
+        let max: MaxVal<_> = block_on(stream::iter(vec![3, 1, 5, 2]).collected());
+        assert_eq!(max.unwrap(), 5);
+
+        let last_n: LastN<Vec<_>, _, 2> = block_on(stream::iter(vec![3, 1, 5, 2]).collected());
+        assert_eq!(last_n.into_inner(), vec![5, 2]);
+
+        let mut pulled = 0;
+        let first: First<_> = block_on(stream::iter(0..10).inspect(|_| pulled += 1).collected());
+        assert_eq!(first.into_inner(), Some(0));
+        assert_eq!(pulled, 1);
+    }
+
+    #[test]
+    fn extend_from_stream_test() {
+        let mut max: MaxVal<usize> = (0..10).collect();
+        block_on(extend_from_stream(&mut max, stream::iter(5..20)));
+        assert_eq!(max.unwrap(), 19);
+
+        let mut first: First<usize> = First::default();
+        block_on(extend_from_stream(&mut first, stream::empty()));
+        block_on(extend_from_stream(&mut first, stream::iter(3..)));
+        assert_eq!(first.into_inner(), Some(3));
+    }
+}
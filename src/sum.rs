@@ -2,6 +2,7 @@ use crate::common::*;
 
 /// A collection taking the summation from values with [`Sum`](Sum) trait.
 #[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SumVal<A>(pub A);
 
 impl<A> FromIterator<A> for SumVal<A>
@@ -37,6 +38,52 @@ where
     }
 }
 
+impl<A> Collector for SumVal<A>
+where
+    A: Sum<A>,
+{
+    type Item = A;
+    type Output = A;
+
+    fn push(&mut self, item: A) {
+        self.extend(iter::once(item));
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.extend(iter::once(other.0));
+    }
+
+    fn finish(self) -> A {
+        self.0
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for SumVal<A>
+where
+    A: Sum<A> + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for SumVal<A>
+where
+    A: Sum<A> + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
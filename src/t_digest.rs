@@ -0,0 +1,343 @@
+use crate::common::*;
+
+/// A collection that estimates quantiles in bounded memory by the merging t-digest.
+///
+/// Values are summarized by weighted centroids, where centroids near the tails
+/// are kept small so that extreme quantiles stay accurate. The compression
+/// parameter `C` controls the accuracy. It keeps about `C` centroids, and the
+/// quantile error shrinks in proportion to `1 / C`.
+///
+/// Incoming values are buffered and compressed into the centroids in batches.
+/// NaN and infinite values are ignored. Sketches from several shards can be merged.
+///
+/// ```rust
+/// use collected::TDigest;
+///
+/// let mut digest: TDigest<u32> = (0..10000).collect();
+/// let p99 = digest.quantile(0.99).unwrap();
+/// assert!((p99 - 9900.0).abs() < 10.0);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TDigest<A, const C: usize = 100> {
+    centroids: Vec<Centroid>,
+    buffer: Vec<Centroid>,
+    count: u64,
+    min: f64,
+    max: f64,
+    _phantom: PhantomData<A>,
+}
+
+impl<A, const C: usize> TDigest<A, C> {
+    /// The number of buffered values that triggers compression.
+    const BUFFER_SIZE: usize = 8 * if C > 0 { C } else { 1 };
+
+    /// Returns the number of collected values.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the minimum value, or `None` if no values are collected.
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    /// Returns the maximum value, or `None` if no values are collected.
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+
+    /// Returns the number of centroids after compressing the buffered values.
+    pub fn num_centroids(&mut self) -> usize {
+        self.compress();
+        self.centroids.len()
+    }
+
+    /// Returns the estimated median, or `None` if no values are collected.
+    pub fn median(&mut self) -> Option<f64> {
+        self.quantile(0.5)
+    }
+
+    /// Returns the estimated quantile `q`, or `None` if no values are collected.
+    ///
+    /// # Panics
+    /// Panics if `q` is not within `[0, 1]`.
+    pub fn quantile(&mut self, q: f64) -> Option<f64> {
+        assert!(
+            (0.0..=1.0).contains(&q),
+            "the quantile must be within [0, 1]"
+        );
+        self.compress();
+        if self.centroids.is_empty() {
+            return None;
+        }
+
+        // Interpolate between the centers of adjacent centroids, where the
+        // minimum and the maximum bound the both ends.
+        let target = q * self.count as f64;
+        let mut prev = (0.0, self.min);
+        let mut cumulative = 0.0;
+
+        for centroid in &self.centroids {
+            let center = cumulative + centroid.weight / 2.0;
+            if target <= center {
+                return Some(interpolate(prev, (center, centroid.mean), target));
+            }
+            prev = (center, centroid.mean);
+            cumulative += centroid.weight;
+        }
+
+        Some(interpolate(prev, (cumulative, self.max), target))
+    }
+
+    fn push_centroid(&mut self, centroid: Centroid) {
+        self.buffer.push(centroid);
+        if self.buffer.len() >= Self::BUFFER_SIZE {
+            self.compress();
+        }
+    }
+
+    /// Merges the buffered values into the centroids.
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+
+        let mut items = mem::take(&mut self.buffer);
+        items.append(&mut self.centroids);
+        items.sort_unstable_by(|lhs, rhs| lhs.mean.total_cmp(&rhs.mean));
+
+        let total = self.count as f64;
+        let compression = C.max(1) as f64;
+        let mut items = items.into_iter();
+        let mut current = items.next().unwrap();
+        let mut weight_so_far = 0.0;
+
+        for next in items {
+            // The size limit `π * sqrt(q * (1 - q)) / C` of the k1 scale
+            // function, compared in squares.
+            let weight = current.weight + next.weight;
+            let q = (weight_so_far + weight / 2.0) / total;
+            let ratio = weight * compression / (total * core::f64::consts::PI);
+
+            if ratio * ratio <= q * (1.0 - q) {
+                current.mean += (next.mean - current.mean) * next.weight / weight;
+                current.weight = weight;
+            } else {
+                weight_so_far += current.weight;
+                self.centroids.push(current);
+                current = next;
+            }
+        }
+        self.centroids.push(current);
+    }
+}
+
+impl<A, const C: usize> Default for TDigest<A, C> {
+    fn default() -> Self {
+        Self {
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            count: 0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, const C: usize> FromIterator<A> for TDigest<A, C>
+where
+    A: ToF64,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut digest = Self::default();
+        digest.extend(iter);
+        digest
+    }
+}
+
+impl<A, const C: usize> Extend<A> for TDigest<A, C>
+where
+    A: ToF64,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, const C: usize> Collector for TDigest<A, C>
+where
+    A: ToF64,
+{
+    type Item = A;
+    type Output = Self;
+
+    fn push(&mut self, item: A) {
+        let value = item.to_f64();
+        if !value.is_finite() {
+            return;
+        }
+
+        self.count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.push_centroid(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+    }
+
+    fn merge(&mut self, other: Self) {
+        let Self {
+            centroids,
+            buffer,
+            count,
+            min,
+            max,
+            ..
+        } = other;
+
+        self.count += count;
+        self.min = self.min.min(min);
+        self.max = self.max.max(max);
+        centroids
+            .into_iter()
+            .chain(buffer)
+            .for_each(|centroid| self.push_centroid(centroid));
+    }
+
+    fn finish(self) -> Self {
+        self
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const C: usize> FromParallelIterator<A> for TDigest<A, C>
+where
+    A: ToF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const C: usize> ParallelExtend<A> for TDigest<A, C>
+where
+    A: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+/// A cluster of values summarized by their mean and weight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+fn interpolate((x0, y0): (f64, f64), (x1, y1): (f64, f64), x: f64) -> f64 {
+    if x1 > x0 {
+        y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+    } else {
+        y1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const N: u64 = 100_000;
+
+    /// Returns `0..N` in a scrambled order.
+    fn values() -> impl Iterator<Item = u64> {
+        (0..N).map(|val| val * 7919 % N)
+    }
+
+    fn assert_accurate<const C: usize>(digest: &mut TDigest<u64, C>, tolerance: f64) {
+        for &q in &[0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999] {
+            let expect = q * (N - 1) as f64;
+            let actual = digest.quantile(q).unwrap();
+            assert!(
+                (actual - expect).abs() <= tolerance * N as f64,
+                "q = {}: {} != {}",
+                q,
+                actual,
+                expect
+            );
+        }
+    }
+
+    #[test]
+    fn t_digest_test() {
+        let mut digest: TDigest<u64> = values().collect();
+        assert_eq!(digest.count(), N);
+        assert_eq!(
+            (digest.min(), digest.max()),
+            (Some(0.0), Some((N - 1) as f64))
+        );
+        assert_eq!(digest.quantile(0.0), Some(0.0));
+        assert_eq!(digest.quantile(1.0), Some((N - 1) as f64));
+        assert!(digest.num_centroids() <= 200);
+        assert_accurate(&mut digest, 0.001);
+
+        let mut digest: TDigest<u64, 20> = values().collect();
+        assert!(digest.num_centroids() <= 40);
+        assert_accurate(&mut digest, 0.005);
+
+        let mut digest: TDigest<f64> = vec![4.0, 1.0, f64::NAN, 3.0, 2.0].into_iter().collect();
+        assert_eq!(digest.count(), 4);
+        assert_eq!(digest.median(), Some(2.5));
+
+        let mut digest: TDigest<f64> = vec![1.0, f64::INFINITY, 2.0, f64::NEG_INFINITY, 3.0]
+            .into_iter()
+            .collect();
+        assert_eq!(digest.count(), 3);
+        assert_eq!((digest.min(), digest.max()), (Some(1.0), Some(3.0)));
+        assert_eq!(digest.quantile(1.0), Some(3.0));
+
+        let mut digest: TDigest<f64> = iter::empty().collect();
+        assert_eq!(digest.median(), None);
+    }
+
+    #[test]
+    fn t_digest_merge_test() {
+        let values: Vec<u64> = values().collect();
+        let mut digest = values
+            .chunks(7000)
+            .map(|chunk| chunk.iter().copied().collect::<TDigest<u64>>())
+            .reduce(|mut lhs, rhs| {
+                lhs.merge(rhs);
+                lhs
+            })
+            .unwrap();
+
+        assert_eq!(digest.count(), N);
+        assert!(digest.num_centroids() <= 200);
+        assert_accurate(&mut digest, 0.001);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn t_digest_serde_test() {
+        let mut digest: TDigest<u64> = values().take(5000).collect();
+        let mut restored: TDigest<u64> =
+            bincode::deserialize(&bincode::serialize(&digest).unwrap()).unwrap();
+
+        digest.extend(values().skip(5000));
+        restored.extend(values().skip(5000));
+        assert_eq!(restored.count(), N);
+        assert_eq!(restored.quantile(0.9), digest.quantile(0.9));
+    }
+}
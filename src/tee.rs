@@ -0,0 +1,165 @@
+use crate::common::*;
+
+/// A collection that clones each item into every collection in the tuple `T`.
+///
+/// It is implemented for tuples of up to 12 collections. Each collection receives
+/// a clone of the incoming item, except the last one that takes the item itself.
+/// It is saturated once every member collection is saturated.
+///
+/// ```rust
+/// use collected::{Count, MaxVal, MinVal, SumVal, Tee};
+///
+/// let Tee((min, max, count, sum)): Tee<(MinVal<_>, MaxVal<_>, Count<_>, SumVal<_>)> =
+///     vec![3, 1, 5, 2, 4].into_iter().collect();
+/// assert_eq!(min.unwrap(), 1);
+/// assert_eq!(max.unwrap(), 5);
+/// assert_eq!(count.get(), 5);
+/// assert_eq!(sum.into_inner(), 15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Tee<T>(pub T);
+
+impl<T> Tee<T> {
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! impl_tee {
+    ($($ty:ident $index:tt,)* ; $last_ty:ident $last_index:tt) => {
+        impl<A, $($ty,)* $last_ty> FromIterator<A> for Tee<($($ty,)* $last_ty,)>
+        where
+            A: Clone,
+            $($ty: Default + Extend<A>,)*
+            $last_ty: Default + Extend<A>,
+        {
+            fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+                let mut tee = Self::default();
+                tee.extend(iter);
+                tee
+            }
+        }
+
+        impl<A, $($ty,)* $last_ty> Extend<A> for Tee<($($ty,)* $last_ty,)>
+        where
+            A: Clone,
+            $($ty: Extend<A>,)*
+            $last_ty: Extend<A>,
+        {
+            fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+                for item in iter {
+                    $((self.0).$index.extend(iter::once(item.clone()));)*
+                    (self.0).$last_index.extend(iter::once(item));
+                }
+            }
+        }
+
+        impl<A, $($ty,)* $last_ty> Collector for Tee<($($ty,)* $last_ty,)>
+        where
+            A: Clone,
+            $($ty: Collector<Item = A>,)*
+            $last_ty: Collector<Item = A>,
+        {
+            type Item = A;
+            type Output = ($($ty::Output,)* $last_ty::Output,);
+
+            fn push(&mut self, item: A) {
+                $((self.0).$index.push(item.clone());)*
+                (self.0).$last_index.push(item);
+            }
+
+            fn merge(&mut self, other: Self) {
+                $((self.0).$index.merge((other.0).$index);)*
+                (self.0).$last_index.merge((other.0).$last_index);
+            }
+
+            fn finish(self) -> Self::Output {
+                ($((self.0).$index.finish(),)* (self.0).$last_index.finish(),)
+            }
+
+            fn is_done(&self) -> bool {
+                $((self.0).$index.is_done() &&)* (self.0).$last_index.is_done()
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl<A, $($ty,)* $last_ty> FromParallelIterator<A> for Tee<($($ty,)* $last_ty,)>
+        where
+            A: Clone + Send,
+            $($ty: Collector<Item = A> + Default + Extend<A> + Send,)*
+            $last_ty: Collector<Item = A> + Default + Extend<A> + Send,
+        {
+            fn from_par_iter<I>(par_iter: I) -> Self
+            where
+                I: IntoParallelIterator<Item = A>,
+            {
+                par::collect(par_iter)
+            }
+        }
+
+        #[cfg(feature = "rayon")]
+        impl<A, $($ty,)* $last_ty> ParallelExtend<A> for Tee<($($ty,)* $last_ty,)>
+        where
+            A: Clone + Send,
+            $($ty: Collector<Item = A> + Default + Extend<A> + Send,)*
+            $last_ty: Collector<Item = A> + Default + Extend<A> + Send,
+        {
+            fn par_extend<I>(&mut self, par_iter: I)
+            where
+                I: IntoParallelIterator<Item = A>,
+            {
+                par::extend(self, par_iter);
+            }
+        }
+    };
+}
+
+impl_tee!(; C1 0);
+impl_tee!(C1 0,; C2 1);
+impl_tee!(C1 0, C2 1,; C3 2);
+impl_tee!(C1 0, C2 1, C3 2,; C4 3);
+impl_tee!(C1 0, C2 1, C3 2, C4 3,; C5 4);
+impl_tee!(C1 0, C2 1, C3 2, C4 3, C5 4,; C6 5);
+impl_tee!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5,; C7 6);
+impl_tee!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5, C7 6,; C8 7);
+impl_tee!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5, C7 6, C8 7,; C9 8);
+impl_tee!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5, C7 6, C8 7, C9 8,; C10 9);
+impl_tee!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5, C7 6, C8 7, C9 8, C10 9,; C11 10);
+impl_tee!(C1 0, C2 1, C3 2, C4 3, C5 4, C6 5, C7 6, C8 7, C9 8, C10 9, C11 10,; C12 11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Count, First, Last, MaxVal, MinVal, SumVal, TopK};
+
+    #[test]
+    fn tee_test() {
+        let mut tee: Tee<(MinVal<_>, MaxVal<_>, Count<_>, SumVal<_>)> =
+            vec![3, 1, 5, 2, 4].into_iter().collect();
+        tee.extend(vec![0, 6]);
+
+        let (min, max, count, sum) = tee.into_inner();
+        assert_eq!(min.unwrap(), 0);
+        assert_eq!(max.unwrap(), 6);
+        assert_eq!(count.get(), 7);
+        assert_eq!(sum.into_inner(), 21);
+
+        let Tee((first,)): Tee<(First<_>,)> = vec!["a", "b"].into_iter().collect();
+        assert_eq!(first.into_inner(), Some("a"));
+    }
+
+    #[test]
+    fn tee_collector_test() {
+        let mut lhs: Tee<(First<usize>, Last<usize>, TopK<usize, 2>)> = (0..5).collect();
+        let rhs: Tee<(First<usize>, Last<usize>, TopK<usize, 2>)> = (5..8).collect();
+        lhs.merge(rhs);
+        lhs.push(3);
+
+        assert_eq!(lhs.finish(), (Some(0), Some(3), vec![7, 6]));
+    }
+}
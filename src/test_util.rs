@@ -0,0 +1,11 @@
+//! Helpers shared by the unit tests.
+
+/// Asserts that two floats are equal up to a relative error of `1e-9`.
+pub(crate) fn assert_close(lhs: f64, rhs: f64) {
+    assert!(
+        (lhs - rhs).abs() <= 1e-9 * rhs.abs().max(1.0),
+        "{} != {}",
+        lhs,
+        rhs
+    );
+}
@@ -0,0 +1,21 @@
+/// Selects which of equal items is kept by collections such as [`ArgMaxVal`](crate::ArgMaxVal).
+pub trait TieBreak {
+    /// Whether a later item replaces an earlier equal item.
+    const KEEP_LAST: bool;
+}
+
+/// Keeps the first occurrence among equal items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct KeepFirst;
+
+impl TieBreak for KeepFirst {
+    const KEEP_LAST: bool = false;
+}
+
+/// Keeps the last occurrence among equal items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct KeepLast;
+
+impl TieBreak for KeepLast {
+    const KEEP_LAST: bool = true;
+}
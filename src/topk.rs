@@ -1,28 +1,34 @@
 use crate::common::*;
 
 /// The collection that retains maximum `K` values.
+///
+/// It maintains a min-heap of at most `K` values internally, so that the
+/// smallest retained value is evicted when a greater value arrives.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TopK<A, const K: usize>
 where
     A: Ord,
 {
-    topk: MinMaxHeap<A>,
+    topk: BinaryHeap<Reverse<A>>,
 }
 
 impl<A, const K: usize> TopK<A, K>
 where
     A: Ord,
 {
-    pub fn get_heap(&self) -> &MinMaxHeap<A> {
-        &self.topk
-    }
-
-    pub fn into_heap(self) -> MinMaxHeap<A> {
-        self.topk
+    /// Iterates over the retained values in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = &A> {
+        self.topk.iter().map(|Reverse(item)| item)
     }
 
+    /// Returns the retained values in descending order.
     pub fn into_sorted_vec(self) -> Vec<A> {
-        self.topk.into_vec_desc()
+        self.topk
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(item)| item)
+            .collect()
     }
 }
 
@@ -31,20 +37,20 @@ where
     A: Ord,
 {
     fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
-        let mut topk = MinMaxHeap::new();
+        let mut topk = BinaryHeap::new();
         let mut iter = iter.into_iter();
 
         for item in &mut iter {
-            topk.push(item);
+            topk.push(Reverse(item));
             if topk.len() > K {
-                topk.pop_min();
+                topk.pop();
                 break;
             }
         }
 
         for item in iter {
-            topk.push(item);
-            topk.pop_min();
+            topk.push(Reverse(item));
+            topk.pop();
         }
 
         Self { topk }
@@ -57,7 +63,7 @@ where
 {
     fn default() -> Self {
         Self {
-            topk: MinMaxHeap::new(),
+            topk: BinaryHeap::new(),
         }
     }
 }
@@ -70,20 +76,73 @@ where
         let mut iter = iter.into_iter();
 
         for item in &mut iter {
-            self.topk.push(item);
+            self.topk.push(Reverse(item));
             if self.topk.len() > K {
-                self.topk.pop_min();
+                self.topk.pop();
                 break;
             }
         }
 
         for item in iter {
-            self.topk.push(item);
-            self.topk.pop_min();
+            self.topk.push(Reverse(item));
+            self.topk.pop();
         }
     }
 }
 
+impl<A, const K: usize> Collector for TopK<A, K>
+where
+    A: Ord,
+{
+    type Item = A;
+    type Output = Vec<A>;
+
+    fn push(&mut self, item: A) {
+        self.topk.push(Reverse(item));
+        if self.topk.len() > K {
+            self.topk.pop();
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        other
+            .topk
+            .into_iter()
+            .for_each(|Reverse(item)| self.push(item));
+    }
+
+    /// Returns the retained values in descending order.
+    fn finish(self) -> Vec<A> {
+        self.into_sorted_vec()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const K: usize> FromParallelIterator<A> for TopK<A, K>
+where
+    A: Ord + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const K: usize> ParallelExtend<A> for TopK<A, K>
+where
+    A: Ord + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,6 +150,21 @@ mod tests {
     #[test]
     fn top_k_test() {
         let topk: TopK<_, 3> = vec![3, 1, 8, 2, 7, 7, 0, 2].into_iter().collect();
+        let mut values: Vec<_> = topk.iter().copied().collect();
+        values.sort_unstable();
+        assert_eq!(values, vec![7, 7, 8]);
         assert_eq!(topk.into_sorted_vec(), vec![8, 7, 7]);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn top_k_serde_test() {
+        let mut topk: TopK<_, 3> = vec![3, 1, 8, 2].into_iter().collect();
+        let mut restored: TopK<_, 3> =
+            bincode::deserialize(&bincode::serialize(&topk).unwrap()).unwrap();
+
+        topk.extend(vec![7, 7, 0, 9]);
+        restored.extend(vec![7, 7, 0, 9]);
+        assert_eq!(restored.into_sorted_vec(), topk.into_sorted_vec());
+    }
 }
@@ -0,0 +1,192 @@
+use crate::common::*;
+
+/// The collection that retains `K` values with the maximum keys given by `F`.
+///
+/// It maintains a min-heap of at most `K` values ordered by their keys. The key
+/// function is called on every comparison, so it is expected to be cheap.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "A: Serialize", deserialize = "A: Deserialize<'de>"))
+)]
+pub struct TopKByKey<A, F, const K: usize>
+where
+    F: KeyFn<A>,
+{
+    topk: BinaryHeap<Reverse<ByKey<A, F>>>,
+}
+
+impl<A, F, const K: usize> TopKByKey<A, F, K>
+where
+    F: KeyFn<A>,
+{
+    /// Returns the retained values in descending order of keys.
+    pub fn into_sorted_vec(self) -> Vec<A> {
+        self.topk
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(ByKey(item, _))| item)
+            .collect()
+    }
+}
+
+impl<A, F, const K: usize> Default for TopKByKey<A, F, K>
+where
+    F: KeyFn<A>,
+{
+    fn default() -> Self {
+        Self {
+            topk: BinaryHeap::new(),
+        }
+    }
+}
+
+impl<A, F, const K: usize> FromIterator<A> for TopKByKey<A, F, K>
+where
+    F: KeyFn<A>,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut topk = Self::default();
+        topk.extend(iter);
+        topk
+    }
+}
+
+impl<A, F, const K: usize> Extend<A> for TopKByKey<A, F, K>
+where
+    F: KeyFn<A>,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, F, const K: usize> Collector for TopKByKey<A, F, K>
+where
+    F: KeyFn<A>,
+{
+    type Item = A;
+    type Output = Vec<A>;
+
+    fn push(&mut self, item: A) {
+        self.topk.push(Reverse(ByKey(item, PhantomData)));
+        if self.topk.len() > K {
+            self.topk.pop();
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        other
+            .topk
+            .into_iter()
+            .for_each(|Reverse(ByKey(item, _))| self.push(item));
+    }
+
+    /// Returns the retained values in descending order of keys.
+    fn finish(self) -> Vec<A> {
+        self.into_sorted_vec()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, F, const K: usize> FromParallelIterator<A> for TopKByKey<A, F, K>
+where
+    A: Send,
+    F: KeyFn<A> + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, F, const K: usize> ParallelExtend<A> for TopKByKey<A, F, K>
+where
+    A: Send,
+    F: KeyFn<A> + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+/// An item ordered by its key.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "A: Serialize", deserialize = "A: Deserialize<'de>"))
+)]
+struct ByKey<A, F>(A, PhantomData<F>);
+
+impl<A, F> PartialEq for ByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        F::key(&self.0) == F::key(&other.0)
+    }
+}
+
+impl<A, F> Eq for ByKey<A, F> where F: KeyFn<A> {}
+
+impl<A, F> PartialOrd for ByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A, F> Ord for ByKey<A, F>
+where
+    F: KeyFn<A>,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        F::key(&self.0).cmp(&F::key(&other.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy)]
+    struct ByLen;
+
+    impl KeyFn<&str> for ByLen {
+        type Key = usize;
+
+        fn key(item: &&str) -> usize {
+            item.len()
+        }
+    }
+
+    #[test]
+    fn top_k_by_key_test() {
+        let mut topk: TopKByKey<_, ByLen, 2> = vec!["a", "abcd", "ab"].into_iter().collect();
+        topk.extend(vec!["abc", ""]);
+        assert_eq!(topk.clone().into_sorted_vec(), vec!["abcd", "abc"]);
+
+        topk.merge(vec!["abcde", "x"].into_iter().collect());
+        assert_eq!(topk.finish(), vec!["abcde", "abcd"]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn top_k_by_key_serde_test() {
+        let topk: TopKByKey<_, ByLen, 2> = vec!["a", "abcd", "ab"].into_iter().collect();
+        let bytes = bincode::serialize(&topk).unwrap();
+        let mut restored: TopKByKey<&str, ByLen, 2> = bincode::deserialize(&bytes).unwrap();
+        restored.extend(vec!["abc", ""]);
+        assert_eq!(restored.into_sorted_vec(), vec!["abcd", "abc"]);
+    }
+}
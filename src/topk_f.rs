@@ -0,0 +1,232 @@
+use crate::common::*;
+
+/// The collection that retains maximum `K` values of `f32` or `f64`.
+///
+/// Values are ordered according to the NaN policy `P`, which defaults to
+/// [`IgnoreNan`]. The number of NaN values seen is counted regardless of the policy.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "A: Serialize", deserialize = "A: Deserialize<'de>"))
+)]
+pub struct TopKF<A, const K: usize, P = IgnoreNan>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    topk: BinaryHeap<Reverse<ByPolicy<A, P>>>,
+    nan_count: usize,
+}
+
+impl<A, const K: usize, P> TopKF<A, K, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    /// Returns the number of NaN values seen.
+    pub fn nan_count(&self) -> usize {
+        self.nan_count
+    }
+
+    /// Returns the retained values in descending order.
+    pub fn into_sorted_vec(self) -> Vec<A> {
+        self.topk
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse(ByPolicy(item, _))| item)
+            .collect()
+    }
+
+    fn push_value(&mut self, item: A) {
+        self.topk.push(Reverse(ByPolicy(item, PhantomData)));
+        if self.topk.len() > K {
+            self.topk.pop();
+        }
+    }
+}
+
+impl<A, const K: usize, P> Default for TopKF<A, K, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    fn default() -> Self {
+        Self {
+            topk: BinaryHeap::new(),
+            nan_count: 0,
+        }
+    }
+}
+
+impl<A, const K: usize, P> FromIterator<A> for TopKF<A, K, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut topk = Self::default();
+        topk.extend(iter);
+        topk
+    }
+}
+
+impl<A, const K: usize, P> Extend<A> for TopKF<A, K, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, const K: usize, P> Collector for TopKF<A, K, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    type Item = A;
+    type Output = Vec<A>;
+
+    fn push(&mut self, item: A) {
+        if item.is_nan() {
+            self.nan_count += 1;
+            if P::SKIP_NAN {
+                return;
+            }
+        }
+        self.push_value(item);
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.nan_count += other.nan_count;
+        other
+            .topk
+            .into_iter()
+            .for_each(|Reverse(ByPolicy(item, _))| self.push_value(item));
+    }
+
+    /// Returns the retained values in descending order.
+    fn finish(self) -> Vec<A> {
+        self.into_sorted_vec()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const K: usize, P> FromParallelIterator<A> for TopKF<A, K, P>
+where
+    A: Float + Send,
+    P: NanPolicy + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, const K: usize, P> ParallelExtend<A> for TopKF<A, K, P>
+where
+    A: Float + Send,
+    P: NanPolicy + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+/// A float ordered by the NaN policy `P`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "A: Serialize", deserialize = "A: Deserialize<'de>"))
+)]
+struct ByPolicy<A, P>(A, PhantomData<P>);
+
+impl<A, P> PartialEq for ByPolicy<A, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    fn eq(&self, other: &Self) -> bool {
+        P::compare(&self.0, &other.0) == Ordering::Equal
+    }
+}
+
+impl<A, P> Eq for ByPolicy<A, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+}
+
+impl<A, P> PartialOrd for ByPolicy<A, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A, P> Ord for ByPolicy<A, P>
+where
+    A: Float,
+    P: NanPolicy,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        P::compare(&self.0, &other.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{NanGreatest, TotalOrder};
+
+    #[test]
+    fn top_k_f_test() {
+        let values = || vec![3.0, f64::NAN, 8.0, -1.0, 7.5, -f64::NAN];
+
+        let mut topk: TopKF<f64, 2> = values().into_iter().collect();
+        assert_eq!(topk.nan_count(), 2);
+        topk.merge(vec![f64::NAN, 9.0].into_iter().collect());
+        assert_eq!(topk.nan_count(), 3);
+        assert_eq!(topk.finish(), vec![9.0, 8.0]);
+
+        let topk: TopKF<f64, 3, NanGreatest> = values().into_iter().collect();
+        let topk = topk.into_sorted_vec();
+        assert!(topk[0].is_nan() && topk[1].is_nan());
+        assert_eq!(topk[2], 8.0);
+
+        let topk: TopKF<f64, 2, TotalOrder> = values().into_iter().collect();
+        let topk = topk.into_sorted_vec();
+        assert!(topk[0].is_nan());
+        assert_eq!(topk[1], 8.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn top_k_f_serde_test() {
+        let topk: TopKF<f64, 2> = vec![3.0, f64::NAN, 8.0].into_iter().collect();
+        let mut restored: TopKF<f64, 2> =
+            bincode::deserialize(&bincode::serialize(&topk).unwrap()).unwrap();
+        assert_eq!(restored.nan_count(), 1);
+        restored.extend(vec![5.0, -1.0]);
+        assert_eq!(restored.into_sorted_vec(), vec![8.0, 5.0]);
+
+        let topk: TopKF<f64, 2, NanGreatest> = vec![3.0, f64::NAN].into_iter().collect();
+        let mut restored: TopKF<f64, 2> =
+            bincode::deserialize(&bincode::serialize(&topk).unwrap()).unwrap();
+        restored.extend(vec![5.0, 4.0]);
+        assert_eq!(restored.into_sorted_vec().len(), 2);
+    }
+}
@@ -6,6 +6,11 @@ use crate::common::*;
 /// built from iterator or extended, it expects unique input values.
 /// Otherwise, it empties out the internal and ignore future values.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "A: Deserialize<'de> + Ord"))
+)]
 pub struct UniqueBTreeSet<A>(Option<BTreeSet<A>>);
 
 impl<A> Default for UniqueBTreeSet<A>
@@ -25,7 +30,7 @@ where
         let set = BTreeSet::new();
         let set = iter.into_iter().try_fold(set, |mut set, item| {
             let ok = set.insert(item);
-            ok.then(|| set)
+            ok.then_some(set)
         });
         Self(set)
     }
@@ -61,6 +66,64 @@ impl<A> UniqueBTreeSet<A> {
     }
 }
 
+impl<A> Collector for UniqueBTreeSet<A>
+where
+    A: Ord,
+{
+    type Item = A;
+    type Output = Option<BTreeSet<A>>;
+
+    fn push(&mut self, item: A) {
+        if let Some(set) = &mut self.0 {
+            if !set.insert(item) {
+                self.0 = None;
+            }
+        }
+    }
+
+    /// Fails the collection if both sets share any value.
+    fn merge(&mut self, other: Self) {
+        match other.0 {
+            Some(other) => other.into_iter().for_each(|item| self.push(item)),
+            None => self.0 = None,
+        }
+    }
+
+    fn finish(self) -> Option<BTreeSet<A>> {
+        self.0
+    }
+
+    fn is_done(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for UniqueBTreeSet<A>
+where
+    A: Ord + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for UniqueBTreeSet<A>
+where
+    A: Ord + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
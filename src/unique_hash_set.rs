@@ -6,6 +6,14 @@ use crate::common::*;
 /// built from iterator or extended, it expects unique input values.
 /// Otherwise, it empties out the internal and ignore future values.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "A: Serialize + Hash + Eq",
+        deserialize = "A: Deserialize<'de> + Hash + Eq"
+    ))
+)]
 pub struct UniqueHashSet<A>(Option<HashSet<A>>);
 
 impl<A> Default for UniqueHashSet<A> {
@@ -22,7 +30,7 @@ where
         let set = HashSet::new();
         let set = iter.into_iter().try_fold(set, |mut set, item| {
             let ok = set.insert(item);
-            ok.then(|| set)
+            ok.then_some(set)
         });
         Self(set)
     }
@@ -58,18 +66,75 @@ impl<A> UniqueHashSet<A> {
     }
 }
 
+impl<A> Collector for UniqueHashSet<A>
+where
+    A: Hash + Eq,
+{
+    type Item = A;
+    type Output = Option<HashSet<A>>;
+
+    fn push(&mut self, item: A) {
+        if let Some(set) = &mut self.0 {
+            if !set.insert(item) {
+                self.0 = None;
+            }
+        }
+    }
+
+    /// Fails the collection if both sets share any value.
+    fn merge(&mut self, other: Self) {
+        match other.0 {
+            Some(other) => other.into_iter().for_each(|item| self.push(item)),
+            None => self.0 = None,
+        }
+    }
+
+    fn finish(self) -> Option<HashSet<A>> {
+        self.0
+    }
+
+    fn is_done(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for UniqueHashSet<A>
+where
+    A: Hash + Eq + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for UniqueHashSet<A>
+where
+    A: Hash + Eq + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use maplit::hashset;
 
     #[test]
     fn unque_hash_set_test() {
         let mut set: UniqueHashSet<usize> = vec![1, 2, 3].into_iter().collect();
-        assert_eq!(set.get(), Some(&hashset! {1, 2, 3}));
+        assert_eq!(set.get(), Some(&(1..=3).collect()));
 
         set.extend(vec![4, 5]);
-        assert_eq!(set.get(), Some(&hashset! {1, 2, 3, 4, 5}));
+        assert_eq!(set.get(), Some(&(1..=5).collect()));
         set.extend(vec![6, 6]);
         assert_eq!(set.get(), None);
     }
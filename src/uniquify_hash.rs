@@ -4,6 +4,7 @@ use crate::common::*;
 ///
 /// The wrapper maintains a [HashSet](HashSet) internally. It inserts incoming value `A`
 /// to collection `U` at most once, and ignores duplicated future values.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UniquifyHash<U, A>
 where
     A: Hash + Eq + Clone,
@@ -34,7 +35,7 @@ where
         let mut set = HashSet::new();
         let inner: U = iter
             .into_iter()
-            .filter_map(|item| set.insert(item.clone()).then(|| item))
+            .filter_map(|item| set.insert(item.clone()).then_some(item))
             .collect();
 
         Self { inner, set }
@@ -51,11 +52,62 @@ where
 
         let iter = iter
             .into_iter()
-            .filter_map(|item| set.insert(item.clone()).then(|| item));
+            .filter_map(|item| set.insert(item.clone()).then_some(item));
         inner.extend(iter);
     }
 }
 
+impl<U, A> Collector for UniquifyHash<U, A>
+where
+    U: Extend<A> + IntoIterator<Item = A>,
+    A: Hash + Eq + Clone,
+{
+    type Item = A;
+    type Output = U;
+
+    fn push(&mut self, item: A) {
+        if self.set.insert(item.clone()) {
+            self.inner.extend(iter::once(item));
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        other.inner.into_iter().for_each(|item| self.push(item));
+    }
+
+    fn finish(self) -> U {
+        self.inner
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<U, A> FromParallelIterator<A> for UniquifyHash<U, A>
+where
+    U: FromIterator<A> + Extend<A> + IntoIterator<Item = A> + Send,
+    A: Hash + Eq + Clone + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<U, A> ParallelExtend<A> for UniquifyHash<U, A>
+where
+    U: FromIterator<A> + Extend<A> + IntoIterator<Item = A> + Send,
+    A: Hash + Eq + Clone + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,4 +126,14 @@ mod tests {
         unique.extend(vec![4]);
         assert_eq!(unique.get(), &vec![1, 2, 3, 4]);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn uniquify_hash_serde_test() {
+        let unique: UniquifyHash<Vec<usize>, _> = vec![1, 2, 2, 3].into_iter().collect();
+        let mut restored: UniquifyHash<Vec<usize>, _> =
+            bincode::deserialize(&bincode::serialize(&unique).unwrap()).unwrap();
+        restored.extend(vec![3, 4, 1]);
+        assert_eq!(restored.get(), &vec![1, 2, 3, 4]);
+    }
 }
@@ -4,6 +4,7 @@ use crate::common::*;
 ///
 /// The wrapper maintains a [BTreeSet](BTreeSet) internally. It inserts incoming value `A`
 /// to collection `U` at most once, and ignores duplicated future values.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UniquifyOrd<U, A>
 where
     A: Ord + Clone,
@@ -34,7 +35,7 @@ where
         let mut set = BTreeSet::new();
         let inner: U = iter
             .into_iter()
-            .filter_map(|item| set.insert(item.clone()).then(|| item))
+            .filter_map(|item| set.insert(item.clone()).then_some(item))
             .collect();
 
         Self { inner, set }
@@ -51,11 +52,62 @@ where
 
         let iter = iter
             .into_iter()
-            .filter_map(|item| set.insert(item.clone()).then(|| item));
+            .filter_map(|item| set.insert(item.clone()).then_some(item));
         inner.extend(iter);
     }
 }
 
+impl<U, A> Collector for UniquifyOrd<U, A>
+where
+    U: Extend<A> + IntoIterator<Item = A>,
+    A: Ord + Clone,
+{
+    type Item = A;
+    type Output = U;
+
+    fn push(&mut self, item: A) {
+        if self.set.insert(item.clone()) {
+            self.inner.extend(iter::once(item));
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        other.inner.into_iter().for_each(|item| self.push(item));
+    }
+
+    fn finish(self) -> U {
+        self.inner
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<U, A> FromParallelIterator<A> for UniquifyOrd<U, A>
+where
+    U: FromIterator<A> + Extend<A> + IntoIterator<Item = A> + Send,
+    A: Ord + Clone + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<U, A> ParallelExtend<A> for UniquifyOrd<U, A>
+where
+    U: FromIterator<A> + Extend<A> + IntoIterator<Item = A> + Send,
+    A: Ord + Clone + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,4 +126,13 @@ mod tests {
         unique.extend(vec![4]);
         assert_eq!(unique.get(), &vec![1, 2, 3, 4]);
     }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn uniquify_ord_serde_test() {
+        let unique: UniquifyOrd<Vec<usize>, _> = vec![1, 2, 2, 3].into_iter().collect();
+        let mut restored: UniquifyOrd<Vec<usize>, _> =
+            bincode::deserialize(&bincode::serialize(&unique).unwrap()).unwrap();
+        restored.extend(vec![3, 4, 1]);
+        assert_eq!(restored.get(), &vec![1, 2, 3, 4]);
+    }
 }
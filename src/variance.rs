@@ -0,0 +1,198 @@
+use crate::common::*;
+
+/// A collection that computes the variance and standard deviation of integer or float values.
+///
+/// It keeps the count, the mean and the sum of squared deviations `M2` by
+/// Welford's online algorithm. Partial states are merged by Chan's parallel formula.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VarianceVal<A> {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    _phantom: PhantomData<A>,
+}
+
+impl<A> VarianceVal<A> {
+    /// Returns the number of collected values.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean, or `None` if no values are collected.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Returns the population variance `M2 / n`, or `None` if no values are collected.
+    pub fn population_variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// Returns the sample variance `M2 / (n - 1)`, or `None` if less than two values are collected.
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+
+    /// Returns the population standard deviation, or `None` if no values are collected.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn population_std_dev(&self) -> Option<f64> {
+        self.population_variance().map(math::sqrt)
+    }
+
+    /// Returns the sample standard deviation, or `None` if less than two values are collected.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn sample_std_dev(&self) -> Option<f64> {
+        self.sample_variance().map(math::sqrt)
+    }
+}
+
+impl<A> Default for VarianceVal<A> {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A> FromIterator<A> for VarianceVal<A>
+where
+    A: ToF64,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let mut variance = Self::default();
+        variance.extend(iter);
+        variance
+    }
+}
+
+impl<A> Extend<A> for VarianceVal<A>
+where
+    A: ToF64,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A> Collector for VarianceVal<A>
+where
+    A: ToF64,
+{
+    type Item = A;
+    type Output = Self;
+
+    fn push(&mut self, item: A) {
+        let value = item.to_f64();
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn merge(&mut self, other: Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = other;
+            return;
+        }
+
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+
+        self.mean += delta * weight;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * weight;
+        self.count = count;
+    }
+
+    fn finish(self) -> Self {
+        self
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for VarianceVal<A>
+where
+    A: ToF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for VarianceVal<A>
+where
+    A: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+
+    #[test]
+    fn variance_test() {
+        let mut var: VarianceVal<i32> = vec![2, 4, 4, 4].into_iter().collect();
+        var.extend(vec![5, 5, 7, 9]);
+        assert_eq!(var.count(), 8);
+        assert_eq!(var.mean(), Some(5.0));
+        assert_eq!(var.population_variance(), Some(4.0));
+        assert_close(var.sample_variance().unwrap(), 32.0 / 7.0);
+
+        let var: VarianceVal<f64> = vec![1.0].into_iter().collect();
+        assert_eq!(var.population_variance(), Some(0.0));
+        assert_eq!(var.sample_variance(), None);
+
+        let var: VarianceVal<f64> = iter::empty().collect();
+        assert_eq!(var.mean(), None);
+        assert_eq!(var.population_variance(), None);
+
+        let var: VarianceVal<f64> = (0..1000).map(|val| 1e9 + val as f64).collect();
+        assert_close(
+            var.population_variance().unwrap(),
+            (1000.0 * 1000.0 - 1.0) / 12.0,
+        );
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn std_dev_test() {
+        let var: VarianceVal<u8> = vec![2, 4, 4, 4, 5, 5, 7, 9].into_iter().collect();
+        assert_eq!(var.population_std_dev(), Some(2.0));
+        assert_close(var.sample_std_dev().unwrap(), (32.0f64 / 7.0).sqrt());
+    }
+
+    #[test]
+    fn variance_merge_test() {
+        let values: Vec<f64> = (0..100).map(|val| (val * val % 17) as f64).collect();
+        let whole: VarianceVal<f64> = values.iter().copied().collect();
+
+        let mut merged: VarianceVal<f64> = values[..30].iter().copied().collect();
+        merged.merge(values[30..].iter().copied().collect());
+        merged.merge(VarianceVal::default());
+
+        assert_eq!(merged.count(), whole.count());
+        assert_close(merged.mean().unwrap(), whole.mean().unwrap());
+        assert_close(
+            merged.population_variance().unwrap(),
+            whole.population_variance().unwrap(),
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::common::*;
+
+/// A collection that computes the weighted mean of `(value, weight)` pairs.
+///
+/// Zero weights are accepted and do not affect the mean. Once a negative or NaN
+/// weight arrives, the collection keeps a [`NegativeWeightError`] with the
+/// position of the item.
+///
+/// ```rust
+/// use collected::WeightedMean;
+///
+/// let mean: WeightedMean<f64> = vec![(1.0, 3.0), (5.0, 1.0)].into_iter().collect();
+/// assert_eq!(mean.get(), Ok(Some(2.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeightedMean<A, W = A> {
+    weight_sum: f64,
+    mean: f64,
+    error: Option<NegativeWeightError>,
+    count: usize,
+    _phantom: PhantomData<(A, W)>,
+}
+
+impl<A, W> WeightedMean<A, W> {
+    /// Returns the weighted mean, or `Ok(None)` if the sum of weights is zero.
+    pub fn get(&self) -> Result<Option<f64>, NegativeWeightError> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok((self.weight_sum > 0.0).then_some(self.mean)),
+        }
+    }
+
+    /// Returns the sum of weights.
+    pub fn weight_sum(&self) -> f64 {
+        self.weight_sum
+    }
+
+    /// Returns the number of collected pairs.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl<A, W> Default for WeightedMean<A, W> {
+    fn default() -> Self {
+        Self {
+            weight_sum: 0.0,
+            mean: 0.0,
+            error: None,
+            count: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, W> FromIterator<(A, W)> for WeightedMean<A, W>
+where
+    A: ToF64,
+    W: ToF64,
+{
+    fn from_iter<T: IntoIterator<Item = (A, W)>>(iter: T) -> Self {
+        let mut mean = Self::default();
+        mean.extend(iter);
+        mean
+    }
+}
+
+impl<A, W> Extend<(A, W)> for WeightedMean<A, W>
+where
+    A: ToF64,
+    W: ToF64,
+{
+    fn extend<T: IntoIterator<Item = (A, W)>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, W> Collector for WeightedMean<A, W>
+where
+    A: ToF64,
+    W: ToF64,
+{
+    type Item = (A, W);
+    type Output = Result<Option<f64>, NegativeWeightError>;
+
+    fn push(&mut self, (value, weight): (A, W)) {
+        let index = self.count;
+        self.count += 1;
+        if self.error.is_some() {
+            return;
+        }
+
+        let weight = weight.to_f64();
+        if weight.is_nan() || weight < 0.0 {
+            self.error = Some(NegativeWeightError { index });
+        } else if weight > 0.0 {
+            self.weight_sum += weight;
+            self.mean += (value.to_f64() - self.mean) * weight / self.weight_sum;
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        if self.error.is_none() {
+            if let Some(NegativeWeightError { index }) = other.error {
+                self.error = Some(NegativeWeightError {
+                    index: self.count + index,
+                });
+            } else if other.weight_sum > 0.0 {
+                self.weight_sum += other.weight_sum;
+                self.mean += (other.mean - self.mean) * other.weight_sum / self.weight_sum;
+            }
+        }
+        self.count += other.count;
+    }
+
+    fn finish(self) -> Self::Output {
+        self.get()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, W> FromParallelIterator<(A, W)> for WeightedMean<A, W>
+where
+    A: ToF64 + Send,
+    W: ToF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = (A, W)>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, W> ParallelExtend<(A, W)> for WeightedMean<A, W>
+where
+    A: ToF64 + Send,
+    W: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = (A, W)>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn weighted_mean_test() {
+        let mut mean: WeightedMean<i32, u32> = vec![(2, 1), (4, 0)].into_iter().collect();
+        mean.extend(vec![(8, 3)]);
+        assert_eq!(mean.get(), Ok(Some(6.5)));
+        assert_eq!(mean.weight_sum(), 4.0);
+
+        let mean: WeightedMean<f64> = vec![(1.0, 0.0)].into_iter().collect();
+        assert_eq!(mean.finish(), Ok(None));
+
+        let mut mean: WeightedMean<f64> = vec![(1.0, 1.0), (2.0, -1.0)].into_iter().collect();
+        mean.extend(vec![(3.0, f64::NAN)]);
+        assert_eq!(mean.finish(), Err(NegativeWeightError { index: 1 }));
+    }
+
+    #[test]
+    fn weighted_mean_merge_test() {
+        let mut lhs: WeightedMean<f64> = vec![(1.0, 1.0), (3.0, 1.0)].into_iter().collect();
+        lhs.merge(WeightedMean::default());
+        lhs.merge(vec![(8.0, 2.0)].into_iter().collect());
+        assert_eq!(lhs.get(), Ok(Some(5.0)));
+
+        lhs.merge(vec![(0.0, 1.0), (0.0, -2.0)].into_iter().collect());
+        assert_eq!(lhs.count(), 5);
+        assert_eq!(lhs.finish(), Err(NegativeWeightError { index: 4 }));
+    }
+}
@@ -0,0 +1,286 @@
+use crate::common::*;
+
+/// The interpretation of weights for the sample variance of [`WeightedVariance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WeightKind {
+    /// Weights count repeated observations. The sum of weights `W` is the
+    /// sample size, and the variance is divided by `W - 1`.
+    Frequency,
+    /// Weights express the reliability of observations. The variance is divided
+    /// by `W - W2 / W`, where `W2` is the sum of squared weights.
+    Reliability,
+}
+
+/// A collection that computes the weighted mean and variance of `(value, weight)` pairs.
+///
+/// It keeps the sums of weights and squared weights, the mean and the weighted
+/// sum of squared deviations `M2`, updated in one pass by West's algorithm.
+/// Partial states are merged by Chan's parallel formula. Zero weights are
+/// accepted. Once a negative or NaN weight arrives, the collection keeps a
+/// [`NegativeWeightError`] with the position of the item.
+///
+/// ```rust
+/// use collected::{WeightKind, WeightedVariance};
+///
+/// let var: WeightedVariance<f64> = vec![(1.0, 2.0), (4.0, 1.0)].into_iter().collect();
+/// assert_eq!(var.population_variance(), Ok(Some(2.0)));
+/// assert_eq!(var.sample_variance(WeightKind::Frequency), Ok(Some(3.0)));
+/// assert_eq!(var.sample_variance(WeightKind::Reliability), Ok(Some(4.5)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeightedVariance<A, W = A> {
+    weight_sum: f64,
+    weight_sq_sum: f64,
+    mean: f64,
+    m2: f64,
+    error: Option<NegativeWeightError>,
+    count: usize,
+    _phantom: PhantomData<(A, W)>,
+}
+
+impl<A, W> WeightedVariance<A, W> {
+    /// Returns the number of collected pairs.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the sum of weights.
+    pub fn weight_sum(&self) -> f64 {
+        self.weight_sum
+    }
+
+    /// Returns the weighted mean, or `Ok(None)` if the sum of weights is zero.
+    pub fn mean(&self) -> Result<Option<f64>, NegativeWeightError> {
+        self.stat(self.weight_sum > 0.0, || self.mean)
+    }
+
+    /// Returns the population variance `M2 / W`, or `Ok(None)` if the sum of
+    /// weights is zero.
+    pub fn population_variance(&self) -> Result<Option<f64>, NegativeWeightError> {
+        self.stat(self.weight_sum > 0.0, || self.m2 / self.weight_sum)
+    }
+
+    /// Returns the unbiased sample variance for the kind of weights, or `Ok(None)`
+    /// if the denominator is not positive.
+    pub fn sample_variance(&self, kind: WeightKind) -> Result<Option<f64>, NegativeWeightError> {
+        let denom = match kind {
+            WeightKind::Frequency => self.weight_sum - 1.0,
+            WeightKind::Reliability if self.weight_sum > 0.0 => {
+                self.weight_sum - self.weight_sq_sum / self.weight_sum
+            }
+            WeightKind::Reliability => 0.0,
+        };
+        self.stat(denom > 0.0, || self.m2 / denom)
+    }
+
+    /// Returns the population standard deviation, or `Ok(None)` if the sum of
+    /// weights is zero.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn population_std_dev(&self) -> Result<Option<f64>, NegativeWeightError> {
+        Ok(self.population_variance()?.map(math::sqrt))
+    }
+
+    /// Returns the sample standard deviation for the kind of weights, or
+    /// `Ok(None)` if the denominator is not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    pub fn sample_std_dev(&self, kind: WeightKind) -> Result<Option<f64>, NegativeWeightError> {
+        Ok(self.sample_variance(kind)?.map(math::sqrt))
+    }
+
+    fn stat<F>(&self, valid: bool, f: F) -> Result<Option<f64>, NegativeWeightError>
+    where
+        F: FnOnce() -> f64,
+    {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(valid.then(f)),
+        }
+    }
+}
+
+impl<A, W> Default for WeightedVariance<A, W> {
+    fn default() -> Self {
+        Self {
+            weight_sum: 0.0,
+            weight_sq_sum: 0.0,
+            mean: 0.0,
+            m2: 0.0,
+            error: None,
+            count: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<A, W> FromIterator<(A, W)> for WeightedVariance<A, W>
+where
+    A: ToF64,
+    W: ToF64,
+{
+    fn from_iter<T: IntoIterator<Item = (A, W)>>(iter: T) -> Self {
+        let mut var = Self::default();
+        var.extend(iter);
+        var
+    }
+}
+
+impl<A, W> Extend<(A, W)> for WeightedVariance<A, W>
+where
+    A: ToF64,
+    W: ToF64,
+{
+    fn extend<T: IntoIterator<Item = (A, W)>>(&mut self, iter: T) {
+        iter.into_iter().for_each(|item| self.push(item));
+    }
+}
+
+impl<A, W> Collector for WeightedVariance<A, W>
+where
+    A: ToF64,
+    W: ToF64,
+{
+    type Item = (A, W);
+    type Output = Self;
+
+    fn push(&mut self, (value, weight): (A, W)) {
+        let index = self.count;
+        self.count += 1;
+        if self.error.is_some() {
+            return;
+        }
+
+        let weight = weight.to_f64();
+        if weight.is_nan() || weight < 0.0 {
+            self.error = Some(NegativeWeightError { index });
+        } else if weight > 0.0 {
+            let value = value.to_f64();
+            self.weight_sum += weight;
+            self.weight_sq_sum += weight * weight;
+
+            let delta = value - self.mean;
+            self.mean += delta * weight / self.weight_sum;
+            self.m2 += weight * delta * (value - self.mean);
+        }
+    }
+
+    fn merge(&mut self, other: Self) {
+        if self.error.is_none() {
+            if let Some(NegativeWeightError { index }) = other.error {
+                self.error = Some(NegativeWeightError {
+                    index: self.count + index,
+                });
+            } else if other.weight_sum > 0.0 {
+                let weight_sum = self.weight_sum + other.weight_sum;
+                let delta = other.mean - self.mean;
+                let ratio = other.weight_sum / weight_sum;
+
+                self.mean += delta * ratio;
+                self.m2 += other.m2 + delta * delta * self.weight_sum * ratio;
+                self.weight_sum = weight_sum;
+                self.weight_sq_sum += other.weight_sq_sum;
+            }
+        }
+        self.count += other.count;
+    }
+
+    fn finish(self) -> Self {
+        self
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, W> FromParallelIterator<(A, W)> for WeightedVariance<A, W>
+where
+    A: ToF64 + Send,
+    W: ToF64 + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = (A, W)>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A, W> ParallelExtend<(A, W)> for WeightedVariance<A, W>
+where
+    A: ToF64 + Send,
+    W: ToF64 + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = (A, W)>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_close;
+    use crate::VarianceVal;
+
+    #[test]
+    fn weighted_variance_test() {
+        // Frequency weights are equivalent to repeated values.
+        let pairs = [(2u32, 1u32), (4, 3), (5, 2), (7, 1), (9, 1)];
+        let mut var: WeightedVariance<u32> = pairs[..2].iter().copied().collect();
+        var.extend(pairs[2..].iter().copied());
+        let expect: VarianceVal<u32> = pairs
+            .iter()
+            .flat_map(|&(value, weight)| iter::repeat(value).take(weight as usize))
+            .collect();
+
+        assert_eq!(var.weight_sum(), 8.0);
+        assert_close(var.mean().unwrap().unwrap(), 5.0);
+        assert_close(
+            var.population_variance().unwrap().unwrap(),
+            expect.population_variance().unwrap(),
+        );
+        assert_close(
+            var.sample_variance(WeightKind::Frequency).unwrap().unwrap(),
+            expect.sample_variance().unwrap(),
+        );
+
+        let var: WeightedVariance<f64> = vec![(1.0, 0.5)].into_iter().collect();
+        assert_eq!(var.sample_variance(WeightKind::Frequency), Ok(None));
+        assert_eq!(var.sample_variance(WeightKind::Reliability), Ok(None));
+
+        let var: WeightedVariance<f64> = vec![(1.0, 1.0), (2.0, -0.5)].into_iter().collect();
+        assert_eq!(var.mean(), Err(NegativeWeightError { index: 1 }));
+    }
+
+    #[test]
+    fn weighted_variance_merge_test() {
+        let pairs: Vec<(f64, f64)> = (0..50)
+            .map(|val| ((val * 13 % 17) as f64, (val % 4) as f64 * 0.5))
+            .collect();
+        let whole: WeightedVariance<f64> = pairs.iter().copied().collect();
+
+        let mut merged: WeightedVariance<f64> = pairs[..20].iter().copied().collect();
+        merged.merge(WeightedVariance::default());
+        merged.merge(pairs[20..].iter().copied().collect());
+
+        assert_eq!(merged.count(), 50);
+        assert_close(
+            merged.mean().unwrap().unwrap(),
+            whole.mean().unwrap().unwrap(),
+        );
+        for &kind in &[WeightKind::Frequency, WeightKind::Reliability] {
+            assert_close(
+                merged.sample_variance(kind).unwrap().unwrap(),
+                whole.sample_variance(kind).unwrap().unwrap(),
+            );
+        }
+
+        merged.merge(vec![(1.0, f64::NAN)].into_iter().collect());
+        assert_eq!(
+            merged.population_variance(),
+            Err(NegativeWeightError { index: 50 })
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use crate::common::*;
+
+/// A collection that sums up integers with wrapping addition, that is, modulo
+/// `2^N` for `N`-bit integers.
+#[derive(Debug, Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WrappingSum<A>(pub A);
+
+impl<A> WrappingSum<A> {
+    pub fn get(&self) -> &A {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> A {
+        self.0
+    }
+}
+
+impl<A> FromIterator<A> for WrappingSum<A>
+where
+    A: Integer,
+{
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self {
+        let sum = iter.into_iter().fold(A::ZERO, A::wrapping_add);
+        Self(sum)
+    }
+}
+
+impl<A> Extend<A> for WrappingSum<A>
+where
+    A: Integer,
+{
+    fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
+        self.0 = iter.into_iter().fold(self.0, A::wrapping_add);
+    }
+}
+
+impl<A> Collector for WrappingSum<A>
+where
+    A: Integer,
+{
+    type Item = A;
+    type Output = A;
+
+    fn push(&mut self, item: A) {
+        self.0 = self.0.wrapping_add(item);
+    }
+
+    fn merge(&mut self, other: Self) {
+        self.push(other.0);
+    }
+
+    fn finish(self) -> A {
+        self.0
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> FromParallelIterator<A> for WrappingSum<A>
+where
+    A: Integer + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::collect(par_iter)
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<A> ParallelExtend<A> for WrappingSum<A>
+where
+    A: Integer + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = A>,
+    {
+        par::extend(self, par_iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapping_sum_test() {
+        let mut sum: WrappingSum<u8> = vec![100, 100].into_iter().collect();
+        assert_eq!(*sum.get(), 200);
+
+        sum.extend(vec![100, 1]);
+        assert_eq!(*sum.get(), 45);
+
+        let mut sum: WrappingSum<i8> = vec![-100, -100].into_iter().collect();
+        sum.merge(WrappingSum(27));
+        assert_eq!(sum.into_inner(), 83);
+    }
+}